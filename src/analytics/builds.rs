@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+use crate::entities::game::player_game_info::PlayerGameInfo;
+use crate::entities::ids::{GodId, ItemId};
+
+use super::{WinRate, Z_95};
+
+/// Number of items in a completed build.
+pub const FULL_BUILD_SIZE: usize = 6;
+
+/// Item, relic and starter choices per god and role, computed from the final builds of
+/// many matches.
+///
+/// Choices seen in fewer than `min_games` games are left out of the results.
+///
+/// ## Example
+/// ```rust
+/// use smite::analytics::builds::BuildAnalysis;
+/// use smite::entities::ids::GodId;
+///
+/// # let rows = Vec::new();
+/// let analysis = BuildAnalysis::from_rows(&rows, 30);
+///
+/// for build in analysis.builds(GodId(1773), Some("Carry")).iter().take(3) {
+///     let (low, high) = build.interval;
+///     println!("{:?}: {} games, {low:.2}-{high:.2} win rate", build.choice, build.games.games);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BuildAnalysis {
+    min_games: u32,
+    slices: HashMap<(GodId, Option<String>), BuildSlice>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct BuildSlice {
+    games: WinRate,
+    builds: HashMap<Vec<ItemId>, WinRate>,
+    items: HashMap<ItemId, WinRate>,
+    relics: HashMap<Vec<ItemId>, WinRate>,
+    starters: HashMap<ItemId, WinRate>,
+}
+
+/// A build choice together with how often it was made and how it performed.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BuildOption<T> {
+    pub choice: T,
+    pub games: WinRate,
+    /// Share of the god's games in the slice in which the choice was made.
+    pub pick_rate: f64,
+    /// 95% confidence interval of the win rate.
+    pub interval: (f64, f64),
+}
+
+impl BuildAnalysis {
+    #[must_use]
+    pub fn new(min_games: u32) -> BuildAnalysis {
+        BuildAnalysis {
+            min_games,
+            ..BuildAnalysis::default()
+        }
+    }
+
+    #[must_use]
+    pub fn from_rows(rows: &[PlayerGameInfo], min_games: u32) -> BuildAnalysis {
+        let mut analysis = BuildAnalysis::new(min_games);
+        for row in rows {
+            analysis.add(row);
+        }
+
+        analysis
+    }
+
+    /// Adds the final build of a single player.
+    pub fn add(&mut self, game: &PlayerGameInfo) {
+        let won = game.has_won();
+
+        let mut items: Vec<ItemId> = game.items().iter().map(|item| item.id).collect();
+        items.sort();
+        let mut relics: Vec<ItemId> = game.relics().iter().map(|relic| relic.id).collect();
+        relics.sort();
+        let starter = game.item_slots()[0];
+
+        for role in [None, Some(game.role.clone())] {
+            let slice = self.slices.entry((game.god_id, role)).or_default();
+
+            slice.games.add(won);
+            if items.len() == FULL_BUILD_SIZE {
+                slice.builds.entry(items.clone()).or_default().add(won);
+            }
+            for item in &items {
+                slice.items.entry(*item).or_default().add(won);
+            }
+            if !relics.is_empty() {
+                slice.relics.entry(relics.clone()).or_default().add(won);
+            }
+            if !starter.is_empty() {
+                slice.starters.entry(starter.id).or_default().add(won);
+            }
+        }
+    }
+
+    /// Returns the games played with the god, in the given role or in any role.
+    #[must_use]
+    pub fn games(&self, god_id: GodId, role: Option<&str>) -> WinRate {
+        self.slice(god_id, role)
+            .map(|slice| slice.games)
+            .unwrap_or_default()
+    }
+
+    /// Returns the full final builds, items sorted by id, most common first.
+    #[must_use]
+    pub fn builds(&self, god_id: GodId, role: Option<&str>) -> Vec<BuildOption<Vec<ItemId>>> {
+        self.options(god_id, role, |slice| &slice.builds)
+    }
+
+    /// Returns the items of the final builds, most common first.
+    #[must_use]
+    pub fn items(&self, god_id: GodId, role: Option<&str>) -> Vec<BuildOption<ItemId>> {
+        self.options(god_id, role, |slice| &slice.items)
+    }
+
+    /// Returns the items of the final builds with the highest lower bound of the win rate
+    /// interval first, which favors items that win often over a large sample.
+    #[must_use]
+    pub fn most_successful_items(
+        &self,
+        god_id: GodId,
+        role: Option<&str>,
+    ) -> Vec<BuildOption<ItemId>> {
+        let mut items = self.items(god_id, role);
+        items.sort_by(|a, b| b.interval.0.total_cmp(&a.interval.0));
+
+        items
+    }
+
+    /// Returns the relic combinations, relics sorted by id, most common first.
+    #[must_use]
+    pub fn relics(&self, god_id: GodId, role: Option<&str>) -> Vec<BuildOption<Vec<ItemId>>> {
+        self.options(god_id, role, |slice| &slice.relics)
+    }
+
+    /// Returns the starter choices, most common first.
+    ///
+    /// The starter is the item in the first slot, where starter items are bought and
+    /// upgraded.
+    #[must_use]
+    pub fn starters(&self, god_id: GodId, role: Option<&str>) -> Vec<BuildOption<ItemId>> {
+        self.options(god_id, role, |slice| &slice.starters)
+    }
+
+    fn slice(&self, god_id: GodId, role: Option<&str>) -> Option<&BuildSlice> {
+        self.slices.get(&(god_id, role.map(str::to_string)))
+    }
+
+    fn options<T: Clone + Ord + Hash>(
+        &self,
+        god_id: GodId,
+        role: Option<&str>,
+        choices: impl Fn(&BuildSlice) -> &HashMap<T, WinRate>,
+    ) -> Vec<BuildOption<T>> {
+        let Some(slice) = self.slice(god_id, role) else {
+            return Vec::new();
+        };
+
+        let total = f64::from(slice.games.games.max(1));
+        let mut options: Vec<BuildOption<T>> = choices(slice)
+            .iter()
+            .filter(|(_, games)| games.games >= self.min_games)
+            .map(|(choice, games)| BuildOption {
+                choice: choice.clone(),
+                games: *games,
+                pick_rate: f64::from(games.games) / total,
+                interval: games.confidence_interval(Z_95),
+            })
+            .collect();
+        options.sort_by(|a, b| {
+            b.games
+                .games
+                .cmp(&a.games.games)
+                .then_with(|| a.choice.cmp(&b.choice))
+        });
+
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BuildAnalysis;
+    use crate::analytics::WinRate;
+    use crate::entities::game::player_game_info::PlayerGameInfo;
+    use crate::entities::ids::{GodId, ItemId};
+    use crate::test_support::{row, set_items};
+
+    /// Sets `ActiveId1..4` in order, clearing the remaining slots.
+    fn set_relics(row: &mut PlayerGameInfo, relics: &[u32]) {
+        let slots = [
+            (&mut row.active_id1, &mut row.item_active_1),
+            (&mut row.active_id2, &mut row.item_active_2),
+            (&mut row.active_id3, &mut row.item_active_3),
+            (&mut row.active_id4, &mut row.item_active_4),
+        ];
+        for (index, (id, name)) in slots.into_iter().enumerate() {
+            let relic = relics.get(index).copied().unwrap_or(0);
+            *id = ItemId(relic);
+            *name = if relic == 0 {
+                String::new()
+            } else {
+                format!("Relic {relic}")
+            };
+        }
+    }
+
+    fn build(
+        match_id: u64,
+        role: &str,
+        won: bool,
+        items: &[u32],
+        relics: &[u32],
+    ) -> PlayerGameInfo {
+        let mut row = row(match_id, 1, 1, role, won);
+        set_items(&mut row, items);
+        set_relics(&mut row, relics);
+
+        row
+    }
+
+    fn rows() -> Vec<PlayerGameInfo> {
+        vec![
+            build(1, "Mid", true, &[10, 6, 5, 4, 3, 2], &[30, 20]),
+            build(2, "Mid", false, &[10, 2, 3, 4, 5, 6], &[20, 30]),
+            build(3, "Solo", true, &[11, 2, 3], &[]),
+        ]
+    }
+
+    fn ids(ids: &[u32]) -> Vec<ItemId> {
+        ids.iter().copied().map(ItemId).collect()
+    }
+
+    #[test]
+    fn slices_games_by_god_and_role() {
+        let analysis = BuildAnalysis::from_rows(&rows(), 1);
+
+        assert_eq!(
+            analysis.games(GodId(1), None),
+            WinRate { games: 3, wins: 2 }
+        );
+        assert_eq!(
+            analysis.games(GodId(1), Some("Mid")),
+            WinRate { games: 2, wins: 1 }
+        );
+        assert_eq!(analysis.games(GodId(1), Some("Jungle")), WinRate::default());
+        assert!(analysis.builds(GodId(2), None).is_empty());
+    }
+
+    #[test]
+    fn groups_full_builds_regardless_of_slot_order() {
+        let analysis = BuildAnalysis::from_rows(&rows(), 1);
+
+        let builds = analysis.builds(GodId(1), None);
+        assert_eq!(builds.len(), 1);
+        assert_eq!(builds[0].choice, ids(&[2, 3, 4, 5, 6, 10]));
+        assert_eq!(builds[0].games, WinRate { games: 2, wins: 1 });
+        assert!((builds[0].pick_rate - 2.0 / 3.0).abs() < f64::EPSILON);
+
+        let relics = analysis.relics(GodId(1), Some("Mid"));
+        assert_eq!(relics.len(), 1);
+        assert_eq!(relics[0].choice, ids(&[20, 30]));
+        assert!((relics[0].pick_rate - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn takes_starters_from_the_first_slot() {
+        let analysis = BuildAnalysis::from_rows(&rows(), 1);
+
+        let starters: Vec<(ItemId, u32)> = analysis
+            .starters(GodId(1), None)
+            .iter()
+            .map(|starter| (starter.choice, starter.games.games))
+            .collect();
+        assert_eq!(starters, [(ItemId(10), 2), (ItemId(11), 1)]);
+    }
+
+    #[test]
+    fn leaves_out_choices_below_min_games() {
+        let analysis = BuildAnalysis::from_rows(&rows(), 3);
+
+        assert!(analysis.builds(GodId(1), None).is_empty());
+        assert!(analysis.starters(GodId(1), None).is_empty());
+        let items: Vec<ItemId> = analysis
+            .items(GodId(1), None)
+            .iter()
+            .map(|item| item.choice)
+            .collect();
+        assert_eq!(items, ids(&[2, 3]));
+    }
+
+    #[test]
+    fn ranks_successful_items_by_interval_lower_bound() {
+        let rows: Vec<PlayerGameInfo> = (1..=6)
+            .map(|match_id| {
+                let won = match_id <= 4;
+                let items: &[u32] = if won { &[100, 200] } else { &[100] };
+                build(match_id, "Mid", won, items, &[])
+            })
+            .collect();
+        let analysis = BuildAnalysis::from_rows(&rows, 1);
+
+        let common: Vec<ItemId> = analysis
+            .items(GodId(1), None)
+            .iter()
+            .map(|item| item.choice)
+            .collect();
+        assert_eq!(common, ids(&[100, 200]));
+
+        let successful: Vec<ItemId> = analysis
+            .most_successful_items(GodId(1), None)
+            .iter()
+            .map(|item| item.choice)
+            .collect();
+        assert_eq!(successful, ids(&[200, 100]));
+    }
+
+    #[test]
+    fn has_no_choices_without_games() {
+        let analysis = BuildAnalysis::from_rows(&[], 0);
+
+        assert_eq!(analysis.games(GodId(1), None), WinRate::default());
+        assert!(analysis.items(GodId(1), None).is_empty());
+        assert!(analysis.most_successful_items(GodId(1), None).is_empty());
+    }
+
+    #[test]
+    fn skips_starter_of_empty_first_slot() {
+        let mut rows = vec![build(1, "Mid", true, &[10, 2], &[])];
+        let mut late = row(2, 1, 1, "Mid", false);
+        late.item_id2 = ItemId(2);
+        late.item_purch_2 = "Item 2".to_string();
+        rows.push(late);
+
+        let analysis = BuildAnalysis::from_rows(&rows, 1);
+
+        let starters = analysis.starters(GodId(1), None);
+        assert_eq!(starters.len(), 1);
+        assert!((starters[0].pick_rate - 0.5).abs() < f64::EPSILON);
+        assert_eq!(analysis.items(GodId(1), None)[0].choice, ItemId(2));
+    }
+
+    #[test]
+    fn breaks_ties_by_choice() {
+        let rows = [build(1, "Mid", true, &[30, 10, 20], &[])];
+
+        let analysis = BuildAnalysis::from_rows(&rows, 1);
+
+        let items: Vec<ItemId> = analysis
+            .items(GodId(1), None)
+            .iter()
+            .map(|item| item.choice)
+            .collect();
+        assert_eq!(items, ids(&[10, 20, 30]));
+        let successful: Vec<ItemId> = analysis
+            .most_successful_items(GodId(1), None)
+            .iter()
+            .map(|item| item.choice)
+            .collect();
+        assert_eq!(successful, ids(&[10, 20, 30]));
+    }
+}
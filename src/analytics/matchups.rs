@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::entities::game::match_details::{Match, Team};
+use crate::entities::ids::GodId;
+
+use super::WinRate;
+
+/// Lane matchup and same-team synergy win rates of gods, computed from many matches.
+///
+/// Rates used to score drafts are shrunk towards 50% by `prior_games` virtual games,
+/// so that pairs seen only a few times don't dominate the score.
+///
+/// ## Example
+/// ```rust
+/// use smite::analytics::matchups::{DraftPick, MatchupAnalysis};
+/// use smite::entities::ids::GodId;
+///
+/// # let matches = Vec::new();
+/// let analysis = MatchupAnalysis::from_matches(&matches);
+///
+/// let team = [DraftPick::new(GodId(1773), "Carry")];
+/// let enemy = [DraftPick::new(GodId(2000), "Carry")];
+/// let score = analysis.score_draft(&team, &enemy);
+/// println!("{:+.3}", score.total());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MatchupAnalysis {
+    prior_games: u32,
+    gods: HashMap<GodId, WinRate>,
+    /// Keyed by lowercase role, god and opposing god, from the point of view of the god.
+    matchups: HashMap<(String, GodId, GodId), WinRate>,
+    /// Keyed by both gods, the lowest id first.
+    synergies: HashMap<(GodId, GodId), WinRate>,
+}
+
+/// A god picked for a role, e.g. `"Mid"`, in a draft.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DraftPick {
+    pub god_id: GodId,
+    pub role: String,
+}
+
+/// Score of a draft against another. Each part is a sum of win rate advantages over 50%.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct DraftScore {
+    /// Advantage of the team's gods over the enemy gods in the same role.
+    pub matchups: f64,
+    /// Advantage of the pairs of gods within the team.
+    pub synergy: f64,
+    /// Advantage of the pairs of gods within the enemy team.
+    pub enemy_synergy: f64,
+}
+
+impl Default for MatchupAnalysis {
+    fn default() -> Self {
+        MatchupAnalysis {
+            prior_games: 10,
+            gods: HashMap::new(),
+            matchups: HashMap::new(),
+            synergies: HashMap::new(),
+        }
+    }
+}
+
+impl MatchupAnalysis {
+    #[must_use]
+    pub fn new() -> MatchupAnalysis {
+        MatchupAnalysis::default()
+    }
+
+    #[must_use]
+    pub fn from_matches(matches: &[Match]) -> MatchupAnalysis {
+        let mut analysis = MatchupAnalysis::new();
+        for game in matches {
+            analysis.add(game);
+        }
+
+        analysis
+    }
+
+    /// Sets the number of virtual 50% games added to every rate when scoring drafts.
+    /// Defaults to 10.
+    #[must_use]
+    pub fn with_prior_games(mut self, prior_games: u32) -> MatchupAnalysis {
+        self.prior_games = prior_games;
+        self
+    }
+
+    /// Adds the picks of a single match.
+    pub fn add(&mut self, game: &Match) {
+        let [first, second] = &game.teams;
+
+        for (team, enemy) in [(first, second), (second, first)] {
+            let won = team.has_won();
+
+            for player in &team.players {
+                self.gods.entry(player.god_id).or_default().add(won);
+
+                let role = player.role.trim().to_ascii_lowercase();
+                if role.is_empty() {
+                    continue;
+                }
+                for opponent in enemy
+                    .players
+                    .iter()
+                    .filter(|opponent| opponent.role.trim().eq_ignore_ascii_case(&role))
+                {
+                    self.matchups
+                        .entry((role.clone(), player.god_id, opponent.god_id))
+                        .or_default()
+                        .add(won);
+                }
+            }
+
+            for (a, b) in god_pairs(team) {
+                self.synergies.entry(pair_key(a, b)).or_default().add(won);
+            }
+        }
+    }
+
+    /// Returns the overall win rate of the god.
+    #[must_use]
+    pub fn god(&self, god_id: GodId) -> WinRate {
+        self.gods.get(&god_id).copied().unwrap_or_default()
+    }
+
+    /// Returns the win rate of `god_id` against `opponent` when both play `role`.
+    #[must_use]
+    pub fn matchup(&self, role: &str, god_id: GodId, opponent: GodId) -> WinRate {
+        self.matchups
+            .get(&(role.trim().to_ascii_lowercase(), god_id, opponent))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the win rate of teams having both gods.
+    #[must_use]
+    pub fn synergy(&self, a: GodId, b: GodId) -> WinRate {
+        self.synergies
+            .get(&pair_key(a, b))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the `count` pairs of gods with the highest win rate together, among pairs
+    /// seen in at least `min_games` games.
+    #[must_use]
+    pub fn best_synergies(&self, min_games: u32, count: usize) -> Vec<((GodId, GodId), WinRate)> {
+        let mut pairs: Vec<((GodId, GodId), WinRate)> = self
+            .synergies
+            .iter()
+            .filter(|(_, games)| games.games >= min_games)
+            .map(|(pair, games)| (*pair, *games))
+            .collect();
+        pairs.sort_by(|a, b| b.1.rate().total_cmp(&a.1.rate()).then(a.0.cmp(&b.0)));
+        pairs.truncate(count);
+
+        pairs
+    }
+
+    /// Scores `team` against `enemy`, usually five picks each. A positive total favors `team`.
+    #[must_use]
+    pub fn score_draft(&self, team: &[DraftPick], enemy: &[DraftPick]) -> DraftScore {
+        let mut matchups = 0.0;
+        for pick in team {
+            for opponent in enemy
+                .iter()
+                .filter(|opponent| opponent.role.trim().eq_ignore_ascii_case(pick.role.trim()))
+            {
+                matchups += self.advantage(self.matchup(&pick.role, pick.god_id, opponent.god_id));
+            }
+        }
+
+        DraftScore {
+            matchups,
+            synergy: self.draft_synergy(team),
+            enemy_synergy: self.draft_synergy(enemy),
+        }
+    }
+
+    fn draft_synergy(&self, picks: &[DraftPick]) -> f64 {
+        let mut synergy = 0.0;
+        for (index, a) in picks.iter().enumerate() {
+            for b in &picks[index + 1..] {
+                synergy += self.advantage(self.synergy(a.god_id, b.god_id));
+            }
+        }
+
+        synergy
+    }
+
+    fn advantage(&self, games: WinRate) -> f64 {
+        let prior = f64::from(self.prior_games);
+        let total = f64::from(games.games) + prior;
+        if total == 0.0 {
+            return 0.0;
+        }
+
+        (f64::from(games.wins) + prior / 2.0) / total - 0.5
+    }
+}
+
+impl DraftPick {
+    #[must_use]
+    pub fn new(god_id: GodId, role: &str) -> DraftPick {
+        DraftPick {
+            god_id,
+            role: role.to_string(),
+        }
+    }
+}
+
+impl DraftScore {
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.matchups + self.synergy - self.enemy_synergy
+    }
+}
+
+fn god_pairs(team: &Team) -> Vec<(GodId, GodId)> {
+    let mut pairs = Vec::new();
+    for (index, a) in team.players.iter().enumerate() {
+        for b in &team.players[index + 1..] {
+            pairs.push((a.god_id, b.god_id));
+        }
+    }
+
+    pairs
+}
+
+fn pair_key(a: GodId, b: GodId) -> (GodId, GodId) {
+    (a.min(b), a.max(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DraftPick, MatchupAnalysis};
+    use crate::analytics::WinRate;
+    use crate::entities::game::match_details::Match;
+    use crate::entities::ids::{GodId, PlayerId};
+    use crate::test_support::game;
+
+    fn matches() -> Vec<Match> {
+        [
+            game(
+                1,
+                &[(1, "Mid"), (2, "Jungle")],
+                &[(3, "mid "), (4, "Jungle")],
+                true,
+            ),
+            game(
+                2,
+                &[(1, "Mid"), (2, "Jungle")],
+                &[(3, "Mid"), (4, "Jungle")],
+                false,
+            ),
+            game(
+                3,
+                &[(1, "Mid"), (4, "Jungle")],
+                &[(3, "Mid"), (2, "Jungle")],
+                true,
+            ),
+        ]
+        .into_iter()
+        .map(|rows| Match::from_players(rows).unwrap())
+        .collect()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn counts_matchups_from_both_sides_ignoring_role_case() {
+        let analysis = MatchupAnalysis::from_matches(&matches());
+
+        assert_eq!(analysis.god(GodId(1)), WinRate { games: 3, wins: 2 });
+        assert_eq!(
+            analysis.matchup("MID", GodId(1), GodId(3)),
+            WinRate { games: 3, wins: 2 }
+        );
+        assert_eq!(
+            analysis.matchup("mid", GodId(3), GodId(1)),
+            WinRate { games: 3, wins: 1 }
+        );
+        assert_eq!(
+            analysis.matchup("Jungle", GodId(4), GodId(2)),
+            WinRate { games: 3, wins: 2 }
+        );
+        assert_eq!(
+            analysis.matchup("Mid", GodId(1), GodId(4)),
+            WinRate::default()
+        );
+    }
+
+    #[test]
+    fn skips_matchups_of_players_without_role() {
+        let game = Match::from_players(game(1, &[(1, "")], &[(3, "")], true)).unwrap();
+        let analysis = MatchupAnalysis::from_matches(&[game]);
+
+        assert_eq!(analysis.god(GodId(1)), WinRate { games: 1, wins: 1 });
+        assert_eq!(analysis.matchup("", GodId(1), GodId(3)), WinRate::default());
+    }
+
+    #[test]
+    fn keys_synergies_by_unordered_pair() {
+        let analysis = MatchupAnalysis::from_matches(&matches());
+
+        assert_eq!(
+            analysis.synergy(GodId(2), GodId(1)),
+            WinRate { games: 2, wins: 1 }
+        );
+        assert_eq!(
+            analysis.synergy(GodId(1), GodId(2)),
+            analysis.synergy(GodId(2), GodId(1))
+        );
+        assert_eq!(
+            analysis.synergy(GodId(1), GodId(4)),
+            WinRate { games: 1, wins: 1 }
+        );
+
+        let best: Vec<(GodId, GodId)> = analysis
+            .best_synergies(2, 5)
+            .into_iter()
+            .map(|(pair, _)| pair)
+            .collect();
+        assert_eq!(best, [(GodId(1), GodId(2)), (GodId(3), GodId(4))]);
+        assert_eq!(analysis.best_synergies(1, 1)[0].0, (GodId(1), GodId(4)));
+    }
+
+    #[test]
+    fn scores_drafts_with_raw_rates_without_prior() {
+        let analysis = MatchupAnalysis::from_matches(&matches()).with_prior_games(0);
+        let team = [
+            DraftPick::new(GodId(1), "Mid"),
+            DraftPick::new(GodId(4), "Jungle"),
+        ];
+        let enemy = [
+            DraftPick::new(GodId(3), "Mid"),
+            DraftPick::new(GodId(2), "Jungle"),
+        ];
+
+        let score = analysis.score_draft(&team, &enemy);
+        assert!(close(score.matchups, 1.0 / 3.0));
+        assert!(close(score.synergy, 0.5));
+        assert!(close(score.enemy_synergy, -0.5));
+        assert!(close(score.total(), 4.0 / 3.0));
+
+        let unseen = [
+            DraftPick::new(GodId(9), "Mid"),
+            DraftPick::new(GodId(8), "Solo"),
+        ];
+        assert!(close(analysis.score_draft(&unseen, &enemy).synergy, 0.0));
+    }
+
+    #[test]
+    fn shrinks_rates_towards_even_with_prior_games() {
+        let analysis = MatchupAnalysis::from_matches(&matches());
+        let team = [
+            DraftPick::new(GodId(1), "Mid"),
+            DraftPick::new(GodId(4), "Jungle"),
+        ];
+
+        let score = analysis.score_draft(&team, &[]);
+        assert!(close(score.matchups, 0.0));
+        assert!(close(score.synergy, 1.0 / 22.0));
+    }
+
+    #[test]
+    fn scores_nothing_without_matches() {
+        let analysis = MatchupAnalysis::from_matches(&[]).with_prior_games(0);
+        let team = [
+            DraftPick::new(GodId(1), "Mid"),
+            DraftPick::new(GodId(2), "Solo"),
+        ];
+
+        assert_eq!(analysis.god(GodId(1)), WinRate::default());
+        assert!(analysis.best_synergies(0, 10).is_empty());
+        assert!(close(analysis.score_draft(&team, &team).total(), 0.0));
+        assert!(close(analysis.score_draft(&[], &[]).total(), 0.0));
+    }
+
+    #[test]
+    fn counts_mirror_matchups_from_both_sides() {
+        let game = Match::from_players(game(1, &[(1, "Mid")], &[(1, "Mid")], true)).unwrap();
+        let analysis = MatchupAnalysis::from_matches(&[game]);
+
+        assert_eq!(analysis.god(GodId(1)), WinRate { games: 2, wins: 1 });
+        assert_eq!(
+            analysis.matchup("Mid", GodId(1), GodId(1)),
+            WinRate { games: 2, wins: 1 }
+        );
+    }
+
+    #[test]
+    fn counts_hidden_players() {
+        let mut rows = game(1, &[(1, "Mid"), (2, "Jungle")], &[(3, "Mid")], true);
+        rows[1].player_id = PlayerId(0);
+        let analysis = MatchupAnalysis::from_matches(&[Match::from_players(rows).unwrap()]);
+
+        assert_eq!(analysis.god(GodId(2)), WinRate { games: 1, wins: 1 });
+        assert_eq!(
+            analysis.synergy(GodId(1), GodId(2)),
+            WinRate { games: 1, wins: 1 }
+        );
+    }
+
+    #[test]
+    fn breaks_synergy_ties_by_pair() {
+        let analysis = MatchupAnalysis::from_matches(&matches());
+
+        let pairs: Vec<(GodId, GodId)> = analysis
+            .best_synergies(1, 10)
+            .into_iter()
+            .map(|(pair, _)| pair)
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                (GodId(1), GodId(4)),
+                (GodId(1), GodId(2)),
+                (GodId(3), GodId(4)),
+                (GodId(2), GodId(3)),
+            ]
+        );
+        assert!(analysis.best_synergies(1, 0).is_empty());
+    }
+}
@@ -0,0 +1,449 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+use serde::Serialize;
+
+use crate::entities::game::player_game_info::PlayerGameInfo;
+use crate::entities::game::Queue;
+use crate::entities::ids::{GodId, MatchId};
+
+use super::WinRate;
+
+/// Pick, ban and win statistics of gods over many matches.
+///
+/// ## Example
+/// ```rust
+/// use smite::analytics::meta::MetaReport;
+/// use smite::entities::game::Queue;
+///
+/// # let rows = Vec::new();
+/// let report = MetaReport::builder()
+///     .queue(&Queue::RankedConquest)
+///     .tiers(20..=27)
+///     .ingest(&rows)
+///     .build();
+///
+/// for god in report.by_pick_rate() {
+///     println!("{}: {:.1}% picked", god.god_name, god.pick_rate * 100.0);
+/// }
+/// ```
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+pub struct MetaReport {
+    /// Number of distinct matches in the slice.
+    pub matches: u32,
+    pub gods: HashMap<GodId, GodMeta>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+pub struct GodMeta {
+    pub god_id: GodId,
+    pub god_name: String,
+    pub picks: WinRate,
+    pub bans: u32,
+    /// Number of matches in which the god was the first ban.
+    pub first_bans: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    /// Share of matches in which the god was picked.
+    pub pick_rate: f64,
+    /// Share of matches in which the god was banned.
+    pub ban_rate: f64,
+    /// Share of matches in which the god was the first ban.
+    pub first_ban_rate: f64,
+}
+
+/// Collects rows into a `MetaReport`, see `MetaReport::builder`.
+///
+/// Queue and tier filters select the matches of the slice, the tier of a match being the
+/// average tier of its players. The role filter only selects which picks are counted,
+/// bans are always counted for every included match.
+#[derive(Debug, Clone, Default)]
+pub struct MetaReportBuilder {
+    queue: Option<Queue>,
+    tiers: Option<RangeInclusive<u32>>,
+    role: Option<String>,
+    matches: HashSet<MatchId>,
+    gods: HashMap<GodId, GodMeta>,
+}
+
+impl MetaReport {
+    #[must_use]
+    pub fn builder() -> MetaReportBuilder {
+        MetaReportBuilder::default()
+    }
+
+    #[must_use]
+    pub fn god(&self, god_id: GodId) -> Option<&GodMeta> {
+        self.gods.get(&god_id)
+    }
+
+    /// Returns all gods, most picked first.
+    #[must_use]
+    pub fn by_pick_rate(&self) -> Vec<&GodMeta> {
+        self.sorted_by(|god| god.pick_rate)
+    }
+
+    /// Returns all gods, most banned first.
+    #[must_use]
+    pub fn by_ban_rate(&self) -> Vec<&GodMeta> {
+        self.sorted_by(|god| god.ban_rate)
+    }
+
+    /// Returns gods picked at least `min_picks` times, highest win rate first.
+    #[must_use]
+    pub fn by_win_rate(&self, min_picks: u32) -> Vec<&GodMeta> {
+        let mut gods = self.sorted_by(|god| god.picks.rate());
+        gods.retain(|god| god.picks.games >= min_picks);
+
+        gods
+    }
+
+    fn sorted_by(&self, key: impl Fn(&GodMeta) -> f64) -> Vec<&GodMeta> {
+        let mut gods: Vec<&GodMeta> = self.gods.values().collect();
+        gods.sort_by(|a, b| key(b).total_cmp(&key(a)).then(a.god_id.cmp(&b.god_id)));
+
+        gods
+    }
+}
+
+impl GodMeta {
+    /// Returns `(kills + assists) / deaths` over all picks, with deaths counted as at least 1.
+    #[must_use]
+    pub fn kda(&self) -> f64 {
+        f64::from(self.kills + self.assists) / f64::from(self.deaths.max(1))
+    }
+}
+
+impl MetaReportBuilder {
+    /// Only includes matches of the given queue.
+    #[must_use]
+    pub fn queue(mut self, queue: &Queue) -> MetaReportBuilder {
+        self.queue = Some(queue.clone());
+        self
+    }
+
+    /// Only includes matches whose average `Conquest_Tier` is within `tiers`.
+    /// Players without a tier are left out of the average.
+    #[must_use]
+    pub fn tiers(mut self, tiers: RangeInclusive<u32>) -> MetaReportBuilder {
+        self.tiers = Some(tiers);
+        self
+    }
+
+    /// Only counts picks of players in the given role, e.g. `"Mid"`.
+    #[must_use]
+    pub fn role(mut self, role: &str) -> MetaReportBuilder {
+        self.role = Some(role.to_string());
+        self
+    }
+
+    /// Adds rows of one or more matches. Rows of already ingested matches are skipped.
+    #[must_use]
+    pub fn ingest(mut self, rows: &[PlayerGameInfo]) -> MetaReportBuilder {
+        let mut included: HashMap<MatchId, Vec<&PlayerGameInfo>> = HashMap::new();
+        for row in rows {
+            included.entry(row.match_id).or_default().push(row);
+        }
+        included
+            .retain(|match_id, players| !self.matches.contains(match_id) && self.includes(players));
+
+        for (match_id, players) in included {
+            self.matches.insert(match_id);
+
+            let first_ban = players[0].ban1_id;
+            for ban in players[0].bans() {
+                let god = self.god_entry(ban.god_id, &ban.god_name);
+                god.bans += 1;
+                if ban.god_id == first_ban {
+                    god.first_bans += 1;
+                }
+            }
+
+            let picks: Vec<&PlayerGameInfo> = players
+                .into_iter()
+                .filter(|row| self.picks_role(row))
+                .collect();
+            for row in picks {
+                let god = self.god_entry(row.god_id, &row.reference_name);
+                god.picks.add(row.has_won());
+                god.kills += row.kills_player;
+                god.deaths += row.deaths;
+                god.assists += row.assists;
+            }
+        }
+
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> MetaReport {
+        let matches = u32::try_from(self.matches.len()).unwrap_or(u32::MAX);
+        let share = |count: u32| {
+            if matches == 0 {
+                0.0
+            } else {
+                f64::from(count) / f64::from(matches)
+            }
+        };
+
+        let gods = self
+            .gods
+            .into_iter()
+            .map(|(god_id, mut god)| {
+                god.pick_rate = share(god.picks.games);
+                god.ban_rate = share(god.bans);
+                god.first_ban_rate = share(god.first_bans);
+                (god_id, god)
+            })
+            .collect();
+
+        MetaReport { matches, gods }
+    }
+
+    fn includes(&self, players: &[&PlayerGameInfo]) -> bool {
+        let queue = &players[0].match_queue_id;
+
+        self.queue.as_ref().map_or(true, |filter| filter == queue)
+            && self.tiers.as_ref().map_or(true, |tiers| {
+                average_tier(players).is_some_and(|tier| tiers.contains(&tier))
+            })
+    }
+
+    fn picks_role(&self, row: &PlayerGameInfo) -> bool {
+        self.role
+            .as_ref()
+            .map_or(true, |role| row.role.eq_ignore_ascii_case(role))
+    }
+
+    fn god_entry(&mut self, god_id: GodId, god_name: &str) -> &mut GodMeta {
+        let god = self.gods.entry(god_id).or_insert_with(|| GodMeta {
+            god_id,
+            ..GodMeta::default()
+        });
+        if god.god_name.is_empty() {
+            god.god_name = god_name.to_string();
+        }
+
+        god
+    }
+}
+
+/// Returns the average `Conquest_Tier` of the players having one, rounded to the nearest tier.
+fn average_tier(players: &[&PlayerGameInfo]) -> Option<u32> {
+    let tiers: Vec<u32> = players
+        .iter()
+        .map(|row| row.conquest_tier)
+        .filter(|tier| *tier != 0)
+        .collect();
+    let count = u32::try_from(tiers.len())
+        .ok()
+        .filter(|count| *count != 0)?;
+
+    Some((tiers.iter().sum::<u32>() + count / 2) / count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MetaReport;
+    use crate::entities::game::player_game_info::PlayerGameInfo;
+    use crate::entities::game::Queue;
+    use crate::entities::ids::{GodId, PlayerId};
+    use crate::test_support::game;
+
+    /// Sets `Ban1..12` in order, clearing the remaining slots.
+    fn set_bans(row: &mut PlayerGameInfo, bans: &[u32]) {
+        let slots = [
+            &mut row.ban1_id,
+            &mut row.ban2_id,
+            &mut row.ban3_id,
+            &mut row.ban4_id,
+            &mut row.ban5_id,
+            &mut row.ban6_id,
+            &mut row.ban7_id,
+            &mut row.ban8_id,
+            &mut row.ban9_id,
+            &mut row.ban10_id,
+            &mut row.ban11_id,
+            &mut row.ban12_id,
+        ];
+        for (index, slot) in slots.into_iter().enumerate() {
+            *slot = GodId(bans.get(index).copied().unwrap_or(0));
+        }
+    }
+
+    fn with_bans(mut rows: Vec<PlayerGameInfo>, bans: &[u32]) -> Vec<PlayerGameInfo> {
+        for row in &mut rows {
+            set_bans(row, bans);
+        }
+
+        rows
+    }
+
+    fn with_tiers(mut rows: Vec<PlayerGameInfo>, tiers: &[u32]) -> Vec<PlayerGameInfo> {
+        for (row, tier) in rows.iter_mut().zip(tiers) {
+            row.conquest_tier = *tier;
+        }
+
+        rows
+    }
+
+    #[test]
+    fn counts_picks_wins_and_kda() {
+        let mut rows = game(
+            1,
+            &[(1, "Mid"), (2, "Solo")],
+            &[(3, "Mid"), (4, "Solo")],
+            true,
+        );
+        rows.extend(game(
+            2,
+            &[(3, "Mid"), (2, "Solo")],
+            &[(1, "Mid"), (5, "Solo")],
+            false,
+        ));
+
+        let report = MetaReport::builder().ingest(&rows).build();
+
+        assert_eq!(report.matches, 2);
+        let god = report.god(GodId(1)).unwrap();
+        assert_eq!((god.picks.games, god.picks.wins), (2, 2));
+        assert!((god.pick_rate - 1.0).abs() < f64::EPSILON);
+        assert!((god.kda() - 8.0).abs() < f64::EPSILON);
+        assert_eq!(report.god(GodId(3)).unwrap().picks.wins, 0);
+    }
+
+    #[test]
+    fn role_filter_only_counts_picks_in_role() {
+        let rows = game(
+            1,
+            &[(1, "Mid"), (2, "Solo")],
+            &[(3, "Mid"), (4, "Solo")],
+            true,
+        );
+
+        let report = MetaReport::builder().role("mid").ingest(&rows).build();
+
+        assert!(report.god(GodId(1)).is_some());
+        assert!(report.god(GodId(2)).is_none());
+    }
+
+    #[test]
+    fn skips_empty_first_ban_slot() {
+        let mut rows = with_bans(game(1, &[(1, "Mid")], &[(2, "Mid")], true), &[0, 5, 6]);
+        rows.extend(with_bans(
+            game(2, &[(1, "Mid")], &[(2, "Mid")], true),
+            &[5, 6],
+        ));
+
+        let report = MetaReport::builder().ingest(&rows).build();
+
+        let banned = report.god(GodId(5)).unwrap();
+        assert_eq!((banned.bans, banned.first_bans), (2, 1));
+        assert!((banned.ban_rate - 1.0).abs() < f64::EPSILON);
+        assert!((banned.first_ban_rate - 0.5).abs() < f64::EPSILON);
+        assert_eq!(report.god(GodId(6)).unwrap().first_bans, 0);
+        assert!(report.god(GodId(0)).is_none());
+    }
+
+    #[test]
+    fn filters_matches_by_average_tier_and_queue() {
+        let team1 = [(1, "Mid"), (2, "Solo")];
+        let team2 = [(3, "Mid"), (4, "Solo")];
+        let mut rows = with_tiers(game(1, &team1, &team2, true), &[27, 5, 5, 5]);
+        rows.extend(with_tiers(game(2, &team1, &team2, true), &[20, 22, 0, 24]));
+
+        let report = MetaReport::builder()
+            .queue(&Queue::Conquest)
+            .tiers(20..=27)
+            .ingest(&rows)
+            .build();
+        assert_eq!(report.matches, 1);
+        assert_eq!(report.god(GodId(1)).unwrap().picks.games, 1);
+
+        let report = MetaReport::builder()
+            .queue(&Queue::RankedConquest)
+            .ingest(&rows)
+            .build();
+        assert_eq!(report.matches, 0);
+    }
+
+    #[test]
+    fn builds_empty_report_without_matches() {
+        let report = MetaReport::builder().tiers(20..=27).ingest(&[]).build();
+
+        assert_eq!(report.matches, 0);
+        assert!(report.gods.is_empty());
+        assert!(report.by_pick_rate().is_empty());
+        assert!(report.by_win_rate(0).is_empty());
+    }
+
+    #[test]
+    fn counts_bans_around_empty_slots() {
+        let rows = with_bans(game(1, &[(1, "Mid")], &[(2, "Mid")], true), &[5, 0, 6]);
+
+        let report = MetaReport::builder().ingest(&rows).build();
+
+        assert_eq!(report.god(GodId(5)).unwrap().first_bans, 1);
+        assert_eq!(report.god(GodId(6)).unwrap().bans, 1);
+        assert!(report.god(GodId(0)).is_none());
+    }
+
+    #[test]
+    fn counts_picks_of_hidden_players() {
+        let mut rows = game(1, &[(1, "Mid")], &[(2, "Mid")], true);
+        rows[1].player_id = PlayerId(0);
+
+        let report = MetaReport::builder().ingest(&rows).build();
+
+        assert_eq!(report.god(GodId(2)).unwrap().picks.games, 1);
+    }
+
+    #[test]
+    fn breaks_rate_ties_by_god_id() {
+        let rows = game(
+            1,
+            &[(4, "Mid"), (2, "Solo")],
+            &[(3, "Mid"), (1, "Solo")],
+            true,
+        );
+
+        let report = MetaReport::builder().ingest(&rows).build();
+
+        let by_pick_rate: Vec<GodId> = report.by_pick_rate().iter().map(|god| god.god_id).collect();
+        assert_eq!(by_pick_rate, [GodId(1), GodId(2), GodId(3), GodId(4)]);
+        let by_win_rate: Vec<GodId> = report.by_win_rate(1).iter().map(|god| god.god_id).collect();
+        assert_eq!(by_win_rate, [GodId(2), GodId(4), GodId(1), GodId(3)]);
+    }
+
+    #[test]
+    fn filters_unknown_queues_and_untiered_matches() {
+        let mut rows = with_tiers(game(1, &[(1, "Mid")], &[(2, "Mid")], true), &[0, 0]);
+        for row in &mut rows {
+            row.match_queue_id = Queue::Other(9999);
+        }
+
+        let report = MetaReport::builder()
+            .queue(&Queue::Other(9999))
+            .ingest(&rows)
+            .build();
+        assert_eq!(report.matches, 1);
+
+        let report = MetaReport::builder()
+            .queue(&Queue::Other(9999))
+            .tiers(0..=27)
+            .ingest(&rows)
+            .build();
+        assert_eq!(report.matches, 0);
+    }
+
+    #[test]
+    fn skips_already_ingested_matches() {
+        let rows = game(1, &[(1, "Mid")], &[(2, "Mid")], true);
+
+        let report = MetaReport::builder().ingest(&rows).ingest(&rows).build();
+
+        assert_eq!(report.matches, 1);
+        assert_eq!(report.god(GodId(1)).unwrap().picks.games, 1);
+    }
+}
@@ -0,0 +1,101 @@
+//! Statistics computed over many matches, e.g. crawled with `get_match_ids_by_queue`
+//! and `get_match_details_batch`.
+pub mod builds;
+pub mod matchups;
+pub mod meta;
+pub mod player_stats;
+
+use serde::Serialize;
+
+/// Z-score of a 95% confidence level, for `WinRate::confidence_interval`.
+pub const Z_95: f64 = 1.96;
+
+/// Number of games and wins in some slice of matches.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WinRate {
+    pub games: u32,
+    pub wins: u32,
+}
+
+impl WinRate {
+    pub fn add(&mut self, won: bool) {
+        self.games += 1;
+        if won {
+            self.wins += 1;
+        }
+    }
+
+    #[must_use]
+    pub fn losses(&self) -> u32 {
+        self.games - self.wins
+    }
+
+    /// Returns the share of games won, from 0 to 1.
+    #[must_use]
+    pub fn rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            f64::from(self.wins) / f64::from(self.games)
+        }
+    }
+
+    /// Returns the Wilson score interval of the win rate for the given z-score, e.g. `Z_95`.
+    /// Unlike the plain rate, the interval widens for small samples.
+    #[must_use]
+    pub fn confidence_interval(&self, z: f64) -> (f64, f64) {
+        if self.games == 0 {
+            return (0.0, 1.0);
+        }
+
+        let games = f64::from(self.games);
+        let rate = self.rate();
+        let z2 = z * z;
+        let center = rate + z2 / (2.0 * games);
+        let margin = z * (rate * (1.0 - rate) / games + z2 / (4.0 * games * games)).sqrt();
+        let denominator = 1.0 + z2 / games;
+
+        (
+            ((center - margin) / denominator).max(0.0),
+            ((center + margin) / denominator).min(1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WinRate, Z_95};
+
+    #[test]
+    fn has_widest_interval_without_games() {
+        let games = WinRate::default();
+
+        assert!(games.rate().abs() < f64::EPSILON);
+        assert_eq!(games.confidence_interval(Z_95), (0.0, 1.0));
+    }
+
+    #[test]
+    fn narrows_interval_with_more_games() {
+        let few = WinRate { games: 4, wins: 2 };
+        let many = WinRate {
+            games: 400,
+            wins: 200,
+        };
+
+        let (few_low, few_high) = few.confidence_interval(Z_95);
+        let (many_low, many_high) = many.confidence_interval(Z_95);
+        assert!(few_low < many_low && many_high < few_high);
+        assert!((many_low + many_high - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn keeps_interval_within_bounds() {
+        let (low, high) = WinRate { games: 3, wins: 3 }.confidence_interval(Z_95);
+        assert!(low > 0.0);
+        assert!((high - 1.0).abs() < f64::EPSILON);
+
+        let (low, high) = WinRate { games: 3, wins: 0 }.confidence_interval(Z_95);
+        assert!(low.abs() < f64::EPSILON);
+        assert!(high < 1.0);
+    }
+}
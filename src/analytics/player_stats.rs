@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::entities::game::player_game_info::PlayerGameInfo;
+use crate::entities::game::Queue;
+use crate::entities::ids::{GodId, ItemId, MatchId, PartyId, PlayerId};
+
+use super::WinRate;
+
+/// Statistics of a single player aggregated over many matches.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+pub struct PlayerStats {
+    pub player_id: PlayerId,
+    pub overall: WinRate,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub by_god: HashMap<GodId, WinRate>,
+    /// Serialized with queue ids as keys, e.g. `"426"` for `Queue::Conquest`.
+    pub by_queue: HashMap<Queue, WinRate>,
+    pub by_role: HashMap<String, WinRate>,
+    /// Win rates keyed by the number of players in the player's party, 1 meaning solo queue.
+    pub by_party_size: HashMap<usize, WinRate>,
+    /// Number of matches each item was part of the final build.
+    pub items: HashMap<ItemId, u32>,
+    pub longest_win_streak: u32,
+    pub longest_loss_streak: u32,
+    /// Streak at the end of the most recent match: positive for wins, negative for losses.
+    pub current_streak: i32,
+}
+
+impl PlayerStats {
+    /// Aggregates the matches of `player_id` found in `rows`.
+    ///
+    /// `rows` may contain other players of the same matches, as returned by
+    /// `get_match_details_batch`; they are used to compute the party size.
+    /// Matches are ordered by id, which grows over time, to compute streaks.
+    ///
+    /// Hidden players all share id `0`, so no matches are aggregated for them.
+    #[must_use]
+    pub fn from_rows(player_id: PlayerId, rows: &[PlayerGameInfo]) -> PlayerStats {
+        let mut party_sizes: HashMap<(MatchId, PartyId), usize> = HashMap::new();
+        for row in rows.iter().filter(|row| !row.party_id.is_solo()) {
+            *party_sizes.entry((row.match_id, row.party_id)).or_default() += 1;
+        }
+
+        let mut games: Vec<&PlayerGameInfo> = rows
+            .iter()
+            .filter(|row| row.player_id == player_id && !player_id.is_hidden())
+            .collect();
+        games.sort_by_key(|row| row.match_id);
+        games.dedup_by_key(|row| row.match_id);
+
+        let mut stats = PlayerStats {
+            player_id,
+            ..PlayerStats::default()
+        };
+
+        for game in games {
+            let party_size = party_sizes
+                .get(&(game.match_id, game.party_id))
+                .copied()
+                .unwrap_or(1);
+            stats.add(game, party_size);
+        }
+
+        stats
+    }
+
+    /// Adds a single match. Matches should be added in chronological order for streaks
+    /// to be correct.
+    pub fn add(&mut self, game: &PlayerGameInfo, party_size: usize) {
+        let won = game.has_won();
+
+        self.overall.add(won);
+        self.kills += game.kills_player;
+        self.deaths += game.deaths;
+        self.assists += game.assists;
+
+        self.by_god.entry(game.god_id).or_default().add(won);
+        self.by_queue
+            .entry(game.match_queue_id.clone())
+            .or_default()
+            .add(won);
+        self.by_role.entry(game.role.clone()).or_default().add(won);
+        self.by_party_size
+            .entry(party_size.max(1))
+            .or_default()
+            .add(won);
+
+        for item in game.items() {
+            *self.items.entry(item.id).or_default() += 1;
+        }
+
+        self.current_streak = match (won, self.current_streak) {
+            (true, streak) if streak > 0 => streak + 1,
+            (true, _) => 1,
+            (false, streak) if streak < 0 => streak - 1,
+            (false, _) => -1,
+        };
+        self.longest_win_streak = self
+            .longest_win_streak
+            .max(u32::try_from(self.current_streak).unwrap_or(0));
+        self.longest_loss_streak = self
+            .longest_loss_streak
+            .max(self.current_streak.min(0).unsigned_abs());
+    }
+
+    /// Returns the win rate in the given queue.
+    #[must_use]
+    pub fn queue(&self, queue: &Queue) -> WinRate {
+        self.by_queue.get(queue).copied().unwrap_or_default()
+    }
+
+    /// Returns `(kills + assists) / deaths` over all matches, with deaths counted as at least 1.
+    #[must_use]
+    pub fn kda(&self) -> f64 {
+        f64::from(self.kills + self.assists) / f64::from(self.deaths.max(1))
+    }
+
+    /// Returns average kills, deaths and assists per match.
+    #[must_use]
+    pub fn average_kda(&self) -> (f64, f64, f64) {
+        let games = f64::from(self.overall.games.max(1));
+
+        (
+            f64::from(self.kills) / games,
+            f64::from(self.deaths) / games,
+            f64::from(self.assists) / games,
+        )
+    }
+
+    /// Returns the `count` items built most often, most built first.
+    #[must_use]
+    pub fn most_built_items(&self, count: usize) -> Vec<(ItemId, u32)> {
+        let mut items: Vec<(ItemId, u32)> = self
+            .items
+            .iter()
+            .map(|(item, built)| (*item, *built))
+            .collect();
+        items.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        items.truncate(count);
+
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlayerStats;
+    use crate::analytics::WinRate;
+    use crate::entities::game::player_game_info::PlayerGameInfo;
+    use crate::entities::game::Queue;
+    use crate::entities::ids::{GodId, ItemId, PartyId, PlayerId};
+    use crate::test_support::{game, set_items};
+
+    /// Player 11 plays god 1 in team 1 of every match.
+    fn matches(results: &[bool]) -> Vec<PlayerGameInfo> {
+        results
+            .iter()
+            .zip(1..)
+            .flat_map(|(won, match_id)| {
+                game(match_id, &[(1, "Mid"), (2, "Solo")], &[(3, "Mid")], *won)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn aggregates_win_rates_and_streaks() {
+        let stats = PlayerStats::from_rows(
+            PlayerId(11),
+            &matches(&[true, true, false, true, true, true]),
+        );
+
+        assert_eq!((stats.overall.games, stats.overall.wins), (6, 5));
+        assert_eq!(stats.by_god[&GodId(1)].games, 6);
+        assert_eq!(stats.by_role["Mid"].wins, 5);
+        assert_eq!(stats.queue(&Queue::Conquest).games, 6);
+        assert_eq!(stats.queue(&Queue::RankedConquest).games, 0);
+        assert_eq!(stats.longest_win_streak, 3);
+        assert_eq!(stats.longest_loss_streak, 1);
+        assert_eq!(stats.current_streak, 3);
+    }
+
+    #[test]
+    fn computes_party_sizes_from_shared_party_ids() {
+        let mut rows = matches(&[true, false]);
+        for row in rows
+            .iter_mut()
+            .filter(|row| row.match_id.0 == 1 && row.task_force == 1)
+        {
+            row.party_id = PartyId(77);
+        }
+
+        let stats = PlayerStats::from_rows(PlayerId(11), &rows);
+
+        assert_eq!(stats.by_party_size[&2].wins, 1);
+        assert_eq!(stats.by_party_size[&1].games, 1);
+    }
+
+    #[test]
+    fn counts_built_items() {
+        let mut rows = matches(&[true, true]);
+        set_items(&mut rows[0], &[100, 200]);
+        set_items(&mut rows[3], &[200]);
+
+        let stats = PlayerStats::from_rows(PlayerId(11), &rows);
+
+        assert_eq!(stats.most_built_items(1), [(ItemId(200), 2)]);
+    }
+
+    #[test]
+    fn serializes_queues_by_id() {
+        let mut rows = matches(&[true]);
+        rows[0].match_queue_id = Queue::Other(9999);
+
+        let stats = PlayerStats::from_rows(PlayerId(11), &rows);
+        let json = serde_json::to_value(&stats).unwrap();
+
+        assert_eq!(json["by_queue"]["9999"]["wins"], 1);
+        assert_eq!(stats.queue(&Queue::Other(9999)).wins, 1);
+    }
+
+    #[test]
+    fn has_empty_stats_without_matches() {
+        let stats = PlayerStats::from_rows(PlayerId(11), &[]);
+
+        assert_eq!(stats.overall, WinRate::default());
+        assert_eq!(stats.current_streak, 0);
+        assert!(stats.kda().abs() < f64::EPSILON);
+        assert_eq!(stats.average_kda(), (0.0, 0.0, 0.0));
+        assert!(stats.most_built_items(3).is_empty());
+    }
+
+    #[test]
+    fn skips_hidden_players() {
+        let mut rows = matches(&[true, false]);
+        for row in &mut rows {
+            row.player_id = PlayerId(0);
+        }
+
+        let stats = PlayerStats::from_rows(PlayerId(0), &rows);
+
+        assert_eq!(stats.overall.games, 0);
+        assert!(stats.by_god.is_empty());
+    }
+
+    #[test]
+    fn counts_duplicated_rows_once() {
+        let mut rows = matches(&[false, true]);
+        rows.extend(matches(&[false]));
+
+        let stats = PlayerStats::from_rows(PlayerId(11), &rows);
+
+        assert_eq!(stats.overall, WinRate { games: 2, wins: 1 });
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.longest_loss_streak, 1);
+    }
+
+    #[test]
+    fn breaks_item_ties_by_id() {
+        let mut rows = matches(&[true]);
+        set_items(&mut rows[0], &[300, 100, 200]);
+
+        let stats = PlayerStats::from_rows(PlayerId(11), &rows);
+
+        assert_eq!(
+            stats.most_built_items(2),
+            [(ItemId(100), 1), (ItemId(200), 1)]
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_queues_apart() {
+        let mut rows = matches(&[true, false]);
+        rows[0].match_queue_id = Queue::Other(9999);
+        rows[3].match_queue_id = Queue::Other(9998);
+
+        let stats = PlayerStats::from_rows(PlayerId(11), &rows);
+
+        assert_eq!(stats.by_queue.len(), 2);
+        assert_eq!(
+            stats.queue(&Queue::Other(9998)),
+            WinRate { games: 1, wins: 0 }
+        );
+        assert_eq!(stats.queue(&Queue::Conquest), WinRate::default());
+    }
+}
@@ -0,0 +1,242 @@
+//! Opt-in caching of static data (gods, items, skins...).
+//!
+//! Static data only changes between patches, but downloading it counts against
+//! the daily request limit. A `Cache` attached to the `Client` with
+//! `Client::with_cache` keeps responses for a configurable time per
+//! `CacheCategory`, and is cleared whenever a new patch version is seen.
+//!
+//! ## Example
+//! ```rust
+//! use chrono::Duration;
+//! use smite::cache::{Cache, CacheCategory, FileBackend};
+//! use smite::client::Client;
+//!
+//! let cache = Cache::new(FileBackend::new("/tmp/smite-cache"))
+//!     .with_ttl(CacheCategory::Gods, Duration::days(7));
+//! let client = Client::new("dev_id".to_string(), "auth_key".to_string()).with_cache(cache);
+//! ```
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_TTL_HOURS: i64 = 24;
+const PATCH_VERSION_KEY: &str = "patch_version";
+/// Prefix of the files written by `FileBackend`, so that only those are cleared.
+const FILE_PREFIX: &str = "smite-cache-";
+
+/// Groups of endpoints sharing the same time-to-live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheCategory {
+    /// `getgods`, `getgodaltabilities` and `getgodrecommendeditems`.
+    Gods,
+    /// `getitems`.
+    Items,
+    /// `getgodskins`.
+    Skins,
+}
+
+/// A raw JSON response stored in a cache backend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedEntry {
+    pub stored_at: DateTime<Utc>,
+    pub value: String,
+}
+
+/// Storage used by the `Cache`.
+///
+/// Backends are best-effort: a failure to read or write an entry is treated as a cache miss.
+pub trait CacheBackend: Send + Sync {
+    fn get(&self, key: &str) -> Option<CachedEntry>;
+    fn set(&self, key: &str, entry: CachedEntry);
+    fn clear(&self);
+}
+
+/// Keeps entries in memory for the lifetime of the `Client`.
+#[derive(Debug, Default)]
+pub struct MemoryBackend {
+    entries: Mutex<HashMap<String, CachedEntry>>,
+}
+
+/// Keeps entries as JSON files in a directory, so they survive restarts.
+/// Files are named `smite-cache-<key>.json`; other files in the directory are left alone.
+#[derive(Debug)]
+pub struct FileBackend {
+    dir: PathBuf,
+}
+
+pub struct Cache {
+    backend: Box<dyn CacheBackend>,
+    ttls: HashMap<CacheCategory, Duration>,
+}
+
+impl Cache {
+    #[must_use]
+    pub fn new(backend: impl CacheBackend + 'static) -> Cache {
+        Cache {
+            backend: Box::new(backend),
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// Cache kept in memory. See `MemoryBackend`.
+    #[must_use]
+    pub fn in_memory() -> Cache {
+        Cache::new(MemoryBackend::default())
+    }
+
+    /// Sets how long responses of the given category stay valid.
+    /// By default, responses are kept for 24 hours.
+    #[must_use]
+    pub fn with_ttl(mut self, category: CacheCategory, ttl: Duration) -> Cache {
+        self.ttls.insert(category, ttl);
+        self
+    }
+
+    #[must_use]
+    pub fn ttl(&self, category: CacheCategory) -> Duration {
+        self.ttls
+            .get(&category)
+            .copied()
+            .unwrap_or_else(|| Duration::hours(DEFAULT_TTL_HOURS))
+    }
+
+    /// Returns the cached response for `key` if it has not expired.
+    #[must_use]
+    pub fn get(&self, category: CacheCategory, key: &str) -> Option<String> {
+        let entry = self.backend.get(key)?;
+        let age = Utc::now().signed_duration_since(entry.stored_at);
+
+        (age < self.ttl(category)).then_some(entry.value)
+    }
+
+    pub fn set(&self, key: &str, value: String) {
+        self.backend.set(
+            key,
+            CachedEntry {
+                stored_at: Utc::now(),
+                value,
+            },
+        );
+    }
+
+    pub fn clear(&self) {
+        self.backend.clear();
+    }
+
+    /// Clears the cache if `version` differs from the last seen patch version.
+    /// Returns `true` if the cache was cleared.
+    #[allow(clippy::must_use_candidate)]
+    pub fn invalidate_for_patch(&self, version: &str) -> bool {
+        let known = self.backend.get(PATCH_VERSION_KEY);
+        if known.as_ref().is_some_and(|entry| entry.value == version) {
+            return false;
+        }
+
+        self.backend.clear();
+        self.set(PATCH_VERSION_KEY, version.to_string());
+
+        known.is_some()
+    }
+}
+
+impl CacheBackend for MemoryBackend {
+    fn get(&self, key: &str) -> Option<CachedEntry> {
+        let entries = self.entries.lock().ok()?;
+        entries.get(key).cloned()
+    }
+
+    fn set(&self, key: &str, entry: CachedEntry) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(key.to_string(), entry);
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+impl FileBackend {
+    /// Stores entries in `dir`. The directory is created on first write.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> FileBackend {
+        FileBackend { dir: dir.into() }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let name: String = key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        self.dir.join(format!("{FILE_PREFIX}{name}.json"))
+    }
+}
+
+impl CacheBackend for FileBackend {
+    fn get(&self, key: &str) -> Option<CachedEntry> {
+        let content = fs::read_to_string(self.path(key)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn set(&self, key: &str, entry: CachedEntry) {
+        let Ok(content) = serde_json::to_string(&entry) else {
+            return;
+        };
+
+        if fs::create_dir_all(&self.dir).is_ok() {
+            let _ = fs::write(self.path(key), content);
+        }
+    }
+
+    fn clear(&self) {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with(FILE_PREFIX) && name.ends_with(".json") {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use chrono::Utc;
+
+    use super::{CacheBackend, CachedEntry, FileBackend};
+
+    #[test]
+    fn file_backend_clears_only_its_own_files() {
+        let dir = std::env::temp_dir().join(format!("smite-cache-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("settings.json"), "{}").unwrap();
+
+        let backend = FileBackend::new(&dir);
+        let entry = CachedEntry {
+            stored_at: Utc::now(),
+            value: "[]".to_string(),
+        };
+        backend.set("getgods/1", entry.clone());
+        assert_eq!(backend.get("getgods/1").unwrap().value, entry.value);
+
+        backend.clear();
+
+        assert!(backend.get("getgods/1").is_none());
+        assert!(dir.join("settings.json").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
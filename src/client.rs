@@ -1,9 +1,10 @@
 use async_mutex::Mutex;
 
+use crate::cache::{Cache, CacheCategory};
 use crate::entities::session::Session;
 use crate::error::Error;
 use crate::error::Result;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 const BASE_URL: &str = "https://api.smitegame.com/smiteapi.svc";
@@ -12,8 +13,13 @@ pub struct Client {
     dev_id: String,
     auth_key: String,
     session: Mutex<Option<Session>>,
+    cache: Option<Cache>,
+    on_defaulted: Option<DefaultedFieldsHook>,
 }
 
+/// Called with the API method and the paths of the fields defaulted in its response.
+type DefaultedFieldsHook = Box<dyn Fn(&str, &[String]) + Send + Sync>;
+
 impl Client {
     #[must_use]
     pub fn new(dev_id: String, auth_key: String) -> Client {
@@ -21,9 +27,42 @@ impl Client {
             dev_id,
             auth_key,
             session: Mutex::new(None),
+            cache: None,
+            on_defaulted: None,
         }
     }
 
+    /// Enables lenient parsing of responses.
+    ///
+    /// Hi-Rez adds and removes fields between patches. In lenient mode a field missing
+    /// from a response, including fields of nested objects, is filled with a default of
+    /// its type (`0`, `""`, `false`, an empty list or object) instead of failing the
+    /// whole request. Fields which can't be defaulted, like dates, still fail it.
+    ///
+    /// `on_defaulted` is called for every response that needed defaults, with the API
+    /// method and the paths of the defaulted fields, e.g. `("getmatchdetails", ["Account_Level"])`.
+    #[must_use]
+    pub fn with_lenient_parsing<F>(mut self, on_defaulted: F) -> Client
+    where
+        F: Fn(&str, &[String]) + Send + Sync + 'static,
+    {
+        self.on_defaulted = Some(Box::new(on_defaulted));
+        self
+    }
+
+    /// Enables caching of static data (gods, items, skins...).
+    /// See the `cache` module for details.
+    #[must_use]
+    pub fn with_cache(mut self, cache: Cache) -> Client {
+        self.cache = Some(cache);
+        self
+    }
+
+    #[must_use]
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
     /// Makes a request to the Hi-Rez API.
     /// The `method` parameter is the name of the API method to call.
     /// The `requires_session` parameter indicates whether the method requires a valid session.
@@ -39,7 +78,7 @@ impl Client {
         additional_args: &[&str],
     ) -> Result<T>
     where
-        for<'a> T: Deserialize<'a>,
+        T: DeserializeOwned,
     {
         let dev_id = self.dev_id.clone();
         let signature = self.signature(method);
@@ -53,15 +92,78 @@ impl Client {
         };
 
         for arg in additional_args {
-            endpoint.push_str(&format!("/{arg}"));
+            endpoint.push('/');
+            endpoint.push_str(arg);
         }
 
-        self._make_request(&endpoint).await
+        self.send_request(method, &endpoint).await
     }
 
-    async fn _make_request<T>(&self, endpoint: &str) -> Result<T>
+    /// Checks whether the API is reachable.
+    /// This method does not require a session, nor does it count towards the request limit.
+    /// Returns the API version and server time reported by the API.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn ping(&self) -> Result<String> {
+        self.send_request("ping", &format!("{BASE_URL}/pingJson"))
+            .await
+    }
+
+    /// Checks whether the current session and signature are valid.
+    /// This method requires a valid session.
+    ///
+    /// The API replies with a message starting with `"This was a successful test"`
+    /// when they are, and with the reason otherwise, e.g. `"Invalid session id."`.
+    ///
+    /// # Errors
+    /// - If the request fails or if the response cannot be parsed.
+    /// - `Error::SmiteApi` with the reply if the test was not successful.
+    pub async fn test_session(&self) -> Result<()> {
+        let reply: String = self.make_request("testsession", true, &[]).await?;
+
+        if reply.starts_with("This was a successful test") {
+            Ok(())
+        } else {
+            Err(Error::SmiteApi(reply))
+        }
+    }
+
+    /// Same as `make_request`, but the response is served from the cache when one is
+    /// configured and the cached response has not expired.
+    pub(crate) async fn make_cached_request<T>(
+        &self,
+        category: CacheCategory,
+        method: &str,
+        additional_args: &[&str],
+    ) -> Result<T>
     where
-        for<'a> T: Deserialize<'a>,
+        T: DeserializeOwned,
+    {
+        let Some(cache) = &self.cache else {
+            return self.make_request(method, true, additional_args).await;
+        };
+
+        let key = std::iter::once(method)
+            .chain(additional_args.iter().copied())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        if let Some(value) = cache.get(category, &key) {
+            if let Ok(parsed) = self.parse(method, &value) {
+                return Ok(parsed);
+            }
+        }
+
+        let value: Value = self.make_request(method, true, additional_args).await?;
+        cache.set(&key, value.to_string());
+
+        self.parse_value(method, value)
+    }
+
+    async fn send_request<T>(&self, method: &str, endpoint: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
     {
         let response = reqwest::get(endpoint).await?.text().await?;
 
@@ -74,7 +176,59 @@ impl Client {
             return Err(Error::SmiteApi(msg.to_string()));
         }
 
-        serde_json::from_str::<T>(&response).map_err(Error::Parsing)
+        self.parse(method, &response)
+    }
+
+    fn parse<T>(&self, method: &str, response: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let err = match serde_json::from_str(response) {
+            Ok(parsed) => return Ok(parsed),
+            Err(err) => err,
+        };
+
+        if self.on_defaulted.is_none() {
+            return Err(Error::Parsing(err));
+        }
+        let Ok(value) = serde_json::from_str(response) else {
+            return Err(Error::Parsing(err));
+        };
+
+        self.parse_leniently(method, value, err)
+    }
+
+    /// Parses a response of `method`. Every response is parsed here or in `parse`, so
+    /// that lenient parsing applies to all of them.
+    pub(crate) fn parse_value<T>(&self, method: &str, value: Value) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        if self.on_defaulted.is_none() {
+            return serde_json::from_value(value).map_err(Error::Parsing);
+        }
+
+        // Deserializing from a reference keeps `value` around for the lenient retry.
+        match T::deserialize(&value) {
+            Ok(parsed) => Ok(parsed),
+            Err(err) => self.parse_leniently(method, value, err),
+        }
+    }
+
+    fn parse_leniently<T>(&self, method: &str, value: Value, err: serde_json::Error) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(on_defaulted) = &self.on_defaulted else {
+            return Err(Error::Parsing(err));
+        };
+        let Some((parsed, defaulted)) = crate::lenient::deserialize_with_defaults(value) else {
+            return Err(Error::Parsing(err));
+        };
+
+        on_defaulted(method, &defaulted);
+
+        Ok(parsed)
     }
 
     pub(crate) async fn request_session(&self) -> Result<Session> {
@@ -85,7 +239,7 @@ impl Client {
             signature = self.signature("createsession"),
             timestamp = current_timestamp()
         );
-        let val: Value = self._make_request(&endpoint).await?;
+        let val: Value = self.send_request("createsession", &endpoint).await?;
 
         let ret_msg = val
             .get("ret_msg")
@@ -93,7 +247,7 @@ impl Client {
             .unwrap_or_default();
 
         if ret_msg == "Approved" {
-            serde_json::from_value(val).map_err(Error::Parsing)
+            self.parse_value("createsession", val)
         } else {
             Err(Error::SmiteApi(ret_msg.to_string()))
         }
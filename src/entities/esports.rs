@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::ids::TeamId;
+use crate::error::Result;
+
+/// A scheduled or played match of the Smite Pro League.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ProLeagueMatch {
+    pub away_team_clan_id: TeamId,
+    pub away_team_name: String,
+    pub away_team_tagname: String,
+    pub home_team_clan_id: TeamId,
+    pub home_team_name: String,
+    pub home_team_tagname: String,
+    pub map_instance_id: String,
+    #[serde(deserialize_with = "crate::utils::timestamp_from_string")]
+    pub match_date: DateTime<Utc>,
+    pub match_number: String,
+    pub match_status: String,
+    pub matchup_id: String,
+    pub region: String,
+    pub tournament_name: String,
+    #[serde(default)]
+    pub week: Option<u32>,
+    pub winning_team_clan_id: TeamId,
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl ProLeagueMatch {
+    /// Returns `true` once a winner has been reported.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.winning_team_clan_id.0 != 0
+    }
+
+    /// Returns the name of the winning team, if the match is finished.
+    #[must_use]
+    pub fn winner_name(&self) -> Option<&str> {
+        if self.winning_team_clan_id.0 == 0 {
+            None
+        } else if self.winning_team_clan_id == self.home_team_clan_id {
+            Some(&self.home_team_name)
+        } else if self.winning_team_clan_id == self.away_team_clan_id {
+            Some(&self.away_team_name)
+        } else {
+            None
+        }
+    }
+}
+
+impl Client {
+    /// Returns the matchup information for each matchup of the current eSports Pro League season.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_pro_league_details(&self) -> Result<Vec<ProLeagueMatch>> {
+        self.make_request("getesportsproleaguedetails", true, &[])
+            .await
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::client::Client;
+use crate::entities::ids::{GodId, MatchId};
+use crate::error::{Error, Result};
+
+use super::player_game_info::PlayerGameInfo;
+use super::Queue;
+
+/// A single match assembled from the per-player rows returned by `getmatchdetails`.
+///
+/// Match-level data (bans, scores, duration, map...) is repeated on every
+/// `PlayerGameInfo` row by the API; here it is stored once, and the players
+/// are split into their two teams.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Match {
+    pub id: MatchId,
+    pub queue: Queue,
+    pub map: String,
+    pub entry_datetime: String,
+    pub duration_seconds: u32,
+    pub first_ban_side: String,
+    pub winning_task_force: u32,
+    pub bans: Vec<Ban>,
+    pub teams: [Team; 2],
+}
+
+/// One of the two sides of a match.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Team {
+    pub task_force: u32,
+    pub score: u32,
+    pub players: Vec<PlayerGameInfo>,
+}
+
+/// A god banned during the draft, in ban order.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Ban {
+    pub god_id: GodId,
+    pub god_name: String,
+}
+
+impl Match {
+    /// Builds a match from the rows of a single match.
+    ///
+    /// # Errors
+    /// - If `players` is empty.
+    /// - If `players` contains rows of more than one match.
+    /// - If a row has a task force other than 1 or 2.
+    pub fn from_players(players: Vec<PlayerGameInfo>) -> Result<Match> {
+        let Some(first) = players.first() else {
+            return Err(Error::InvalidArgument {
+                given: "no players".to_string(),
+                expected: "players of a single match".to_string(),
+            });
+        };
+
+        let id = first.match_id;
+        if let Some(other) = players.iter().find(|player| player.match_id != id) {
+            return Err(Error::InvalidArgument {
+                given: format!("players of matches {id} and {}", other.match_id),
+                expected: "players of a single match".to_string(),
+            });
+        }
+        if let Some(other) = players
+            .iter()
+            .find(|player| !matches!(player.task_force, 1 | 2))
+        {
+            return Err(Error::InvalidArgument {
+                given: format!("task force {}", other.task_force),
+                expected: "task force 1 or 2".to_string(),
+            });
+        }
+
+        let mut team1 = Team {
+            task_force: 1,
+            score: first.team1_score,
+            players: Vec::new(),
+        };
+        let mut team2 = Team {
+            task_force: 2,
+            score: first.team2_score,
+            players: Vec::new(),
+        };
+
+        let queue = first.match_queue_id.clone();
+        let map = first.map_game.clone();
+        let entry_datetime = first.entry_datetime.clone();
+        let duration_seconds = first.match_duration;
+        let first_ban_side = first.first_ban_side.clone();
+        let winning_task_force = first.winning_task_force;
+        let bans = first.bans();
+
+        for player in players {
+            if player.task_force == 2 {
+                team2.players.push(player);
+            } else {
+                team1.players.push(player);
+            }
+        }
+
+        Ok(Match {
+            id,
+            queue,
+            map,
+            entry_datetime,
+            duration_seconds,
+            first_ban_side,
+            winning_task_force,
+            bans,
+            teams: [team1, team2],
+        })
+    }
+
+    /// Groups rows of possibly many matches (e.g. from `get_match_details_batch`)
+    /// into matches, preserving the order in which matches first appear.
+    /// Matches that can't be built, e.g. because of an unknown task force, are skipped.
+    #[must_use]
+    pub fn group(players: Vec<PlayerGameInfo>) -> Vec<Match> {
+        let mut grouped: Vec<Vec<PlayerGameInfo>> = Vec::new();
+        let mut index: HashMap<MatchId, usize> = HashMap::new();
+
+        for player in players {
+            let position = *index.entry(player.match_id).or_insert_with(|| {
+                grouped.push(Vec::new());
+                grouped.len() - 1
+            });
+            grouped[position].push(player);
+        }
+
+        grouped
+            .into_iter()
+            .filter_map(|rows| Match::from_players(rows).ok())
+            .collect()
+    }
+
+    /// Returns the team with the given task force (1 or 2).
+    #[must_use]
+    pub fn team(&self, task_force: u32) -> Option<&Team> {
+        self.teams.iter().find(|team| team.task_force == task_force)
+    }
+
+    #[must_use]
+    pub fn winner(&self) -> Option<&Team> {
+        self.team(self.winning_task_force)
+    }
+
+    #[must_use]
+    pub fn loser(&self) -> Option<&Team> {
+        self.teams
+            .iter()
+            .find(|team| team.task_force != self.winning_task_force)
+    }
+
+    pub fn players(&self) -> impl Iterator<Item = &PlayerGameInfo> {
+        self.teams.iter().flat_map(|team| team.players.iter())
+    }
+}
+
+impl Team {
+    #[must_use]
+    pub fn total_gold(&self) -> u64 {
+        self.players
+            .iter()
+            .map(|player| u64::from(player.gold_earned))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn total_damage(&self) -> u64 {
+        self.players
+            .iter()
+            .map(|player| u64::from(player.damage_player))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn total_kills(&self) -> u32 {
+        self.players.iter().map(|player| player.kills_player).sum()
+    }
+
+    #[must_use]
+    pub fn total_deaths(&self) -> u32 {
+        self.players.iter().map(|player| player.deaths).sum()
+    }
+
+    #[must_use]
+    pub fn has_won(&self) -> bool {
+        self.players.first().is_some_and(PlayerGameInfo::has_won)
+    }
+}
+
+impl Client {
+    /// Retrieves the details of a match and assembles them into a `Match`.
+    ///
+    /// # Errors
+    /// - If the API request fails.
+    /// - If the API returns no players for the match.
+    pub async fn get_match(&self, match_id: impl Into<MatchId>) -> Result<Match> {
+        let players = self.get_match_details(match_id).await?;
+
+        Match::from_players(players)
+    }
+}
@@ -1,40 +1,82 @@
+pub mod match_details;
+pub mod performance;
 pub mod player_game_info;
+pub mod top_matches;
 
+use futures_util::future::try_join_all;
 use player_game_info::PlayerGameInfo;
-use serde::Deserialize;
-
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{Map, Value};
 
 use crate::client::Client;
+use crate::entities::ids;
 use crate::error::{Error, Result};
 
 use super::player::Player;
 
-#[derive(Debug, Clone)]
+/// A game queue, serialized as its queue id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Queue {
     Assault,
     Arena,
     Joust,
     Conquest,
     Motd,
+    RankedConquest,
+    RankedJoust,
+    RankedDuel,
     Other(u32),
 }
 
-#[derive(Debug)]
-pub struct MatchId {
-    pub id: usize,
+/// A match listed by `getmatchidsbyqueue`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct QueueMatch {
+    #[serde(rename(deserialize = "Match"), alias = "id")]
+    pub id: ids::MatchId,
     pub ret_msg: Option<String>,
+    #[serde(
+        rename(deserialize = "Active_Flag"),
+        deserialize_with = "crate::utils::bool_from_string",
+        alias = "active_flag"
+    )]
     pub active_flag: bool,
 }
 
-#[derive(Debug, Deserialize)]
+/// Former name of `QueueMatch`.
+#[deprecated(note = "renamed to `QueueMatch`; the match id itself is `ids::MatchId`")]
+pub type MatchId = QueueMatch;
+
+/// Fields not known to `Player` are kept in `player_info.additional_fields`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(from = "MatchInfoFields")]
 pub struct MatchInfo {
     #[serde(flatten)]
     pub player_info: Player,
-    #[serde(flatten)]
+    /// Copy of `player_info.additional_fields`, not serialized.
+    #[deprecated(note = "use `player_info.additional_fields`")]
+    #[serde(skip_serializing)]
     pub additional_fields: Map<String, Value>,
 }
 
+#[derive(Deserialize)]
+struct MatchInfoFields {
+    #[serde(flatten)]
+    player_info: Player,
+}
+
+impl From<MatchInfoFields> for MatchInfo {
+    #[allow(deprecated)]
+    fn from(fields: MatchInfoFields) -> Self {
+        MatchInfo {
+            additional_fields: fields.player_info.additional_fields.clone(),
+            player_info: fields.player_info,
+        }
+    }
+}
+
+/// Maximum number of ids accepted by a single `getmatchdetailsbatch` request.
+pub const MATCH_BATCH_LIMIT: usize = 10;
+
 impl Client {
     /// Retrieves IDs of matches by queue and date.
     /// The date must be in the format `MM-DD-YYYY`.
@@ -50,7 +92,7 @@ impl Client {
         queue: Queue,
         date: &str,
         hour: Option<i8>,
-    ) -> Result<Vec<MatchId>> {
+    ) -> Result<Vec<QueueMatch>> {
         let hour = hour.unwrap_or(-1);
 
         if !(-1..=23).contains(&hour) {
@@ -76,9 +118,9 @@ impl Client {
     /// - If the API request fails.
     pub async fn get_match_details(
         &self,
-        match_id: impl Into<usize>,
+        match_id: impl Into<ids::MatchId>,
     ) -> Result<Vec<PlayerGameInfo>> {
-        let match_id: usize = match_id.into();
+        let match_id: ids::MatchId = match_id.into();
 
         self.make_request("getmatchdetails", true, &[&match_id.to_string()])
             .await
@@ -86,78 +128,104 @@ impl Client {
 
     /// This is a batch version of `get_match_details`.
     /// It retrieves the details of multiple matches by their IDs.
+    /// Ids are split into chunks of `MATCH_BATCH_LIMIT`, the most a single request
+    /// accepts, which are requested concurrently.
     ///
     /// # Errors
-    /// - If the API request fails.
+    /// - If any of the API requests fails.
     pub async fn get_match_details_batch(
         &self,
-        match_ids: Vec<usize>,
+        match_ids: Vec<ids::MatchId>,
     ) -> Result<Vec<PlayerGameInfo>> {
-        let match_ids: Vec<String> = match_ids.iter().map(ToString::to_string).collect();
+        if match_ids.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        self.make_request("getmatchdetailsbatch", true, &[&match_ids.join(",")])
-            .await
+        let requests = match_ids.chunks(MATCH_BATCH_LIMIT).map(|chunk| {
+            let match_ids = chunk
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+
+            async move {
+                self.make_request::<Vec<PlayerGameInfo>>(
+                    "getmatchdetailsbatch",
+                    true,
+                    &[&match_ids],
+                )
+                .await
+            }
+        });
+
+        Ok(try_join_all(requests)
+            .await?
+            .into_iter()
+            .flatten()
+            .collect())
     }
 }
 
 impl Queue {
-    fn to_id(&self) -> u32 {
+    pub(crate) fn to_id(&self) -> u32 {
         match self {
             Queue::Assault => 445,
             Queue::Motd => 434,
             Queue::Arena => 435,
             Queue::Joust => 448,
             Queue::Conquest => 426,
+            Queue::RankedConquest => 451,
+            Queue::RankedJoust => 450,
+            Queue::RankedDuel => 440,
             Queue::Other(id) => *id,
         }
     }
+
+    #[must_use]
+    pub fn is_ranked(&self) -> bool {
+        matches!(
+            self,
+            Queue::RankedConquest | Queue::RankedJoust | Queue::RankedDuel
+        )
+    }
 }
 
-impl<'de> Deserialize<'de> for MatchId {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+impl From<u32> for Queue {
+    fn from(id: u32) -> Self {
+        match id {
+            445 => Queue::Assault,
+            434 => Queue::Motd,
+            435 => Queue::Arena,
+            448 => Queue::Joust,
+            426 => Queue::Conquest,
+            451 => Queue::RankedConquest,
+            450 => Queue::RankedJoust,
+            440 => Queue::RankedDuel,
+            id => Queue::Other(id),
+        }
+    }
+}
+
+impl Serialize for Queue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
-        D: serde::Deserializer<'de>,
+        S: Serializer,
     {
-        let val: Value = Deserialize::deserialize(deserializer)?;
-
-        let Some(id) = val.get("Match") else {
-            return Err(serde::de::Error::missing_field("Match"));
-        };
+        serializer.serialize_u32(self.to_id())
+    }
+}
 
-        let Some(ret_msg) = val.get("ret_msg").map(|ret_msg| {
-            if ret_msg.is_string() {
-                Some(ret_msg.as_str().unwrap().to_string())
-            } else {
-                None
-            }
-        }) else {
-            return Err(serde::de::Error::missing_field("ret_msg"));
-        };
-
-        let Some(active_flag) = val.get("Active_Flag").and_then(|val| val.as_str()) else {
-            return Err(serde::de::Error::missing_field("Active_Flag"));
-        };
-
-        let Some(id) = id
-            .as_str()
-            .map(str::parse::<usize>)
-            .and_then(std::result::Result::ok)
-        else {
-            return Err(serde::de::Error::custom(
-                "Match ID is not a String parseable to number",
-            ));
-        };
-
-        Ok(MatchId {
-            id,
-            ret_msg,
-            active_flag: active_flag == "y",
-        })
+impl<'de> Deserialize<'de> for Queue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::utils::deserialize_string_or_number::<_, u32>(deserializer).map(Queue::from)
     }
 }
 
-impl From<MatchId> for usize {
-    fn from(value: MatchId) -> Self {
+impl From<QueueMatch> for ids::MatchId {
+    fn from(value: QueueMatch) -> Self {
         value.id
     }
 }
@@ -0,0 +1,219 @@
+use serde::Serialize;
+
+use super::match_details::{Match, Team};
+use super::player_game_info::PlayerGameInfo;
+
+/// Statistics derived from a single `PlayerGameInfo`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PerformanceSummary {
+    /// `(kills + assists) / deaths`, with deaths counted as at least 1.
+    pub kda: f64,
+    /// Share of the team's kills the player took part in, from 0 to 1.
+    /// Only available when the team is known.
+    pub kill_participation: Option<f64>,
+    /// Share of the team's player damage dealt by the player, from 0 to 1.
+    /// Only available when the team is known.
+    pub damage_share: Option<f64>,
+    pub damage_per_minute: f64,
+    pub gold_per_minute: f64,
+    pub mitigated_per_minute: f64,
+    pub healing_per_minute: f64,
+    /// Share of the match the player spent dead, from 0 to 1.
+    pub time_dead: f64,
+}
+
+impl PlayerGameInfo {
+    /// Computes performance metrics which do not depend on the player's team.
+    #[must_use]
+    pub fn performance(&self) -> PerformanceSummary {
+        let minutes = self.minutes_played();
+        let per_minute = |value: u32| {
+            if minutes > 0.0 {
+                f64::from(value) / minutes
+            } else {
+                0.0
+            }
+        };
+
+        let time_dead = if self.time_in_match_seconds > 0 {
+            f64::from(self.time_dead_seconds) / f64::from(self.time_in_match_seconds)
+        } else {
+            0.0
+        };
+
+        PerformanceSummary {
+            kda: self.kda(),
+            kill_participation: None,
+            damage_share: None,
+            damage_per_minute: per_minute(self.damage_player),
+            gold_per_minute: per_minute(self.gold_earned),
+            mitigated_per_minute: per_minute(self.damage_mitigated),
+            healing_per_minute: per_minute(self.healing),
+            time_dead: time_dead.min(1.0),
+        }
+    }
+
+    /// Computes performance metrics, including the ones relative to `team`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn performance_in(&self, team: &Team) -> PerformanceSummary {
+        let team_kills = team.total_kills();
+        let team_damage = team.total_damage();
+
+        PerformanceSummary {
+            kill_participation: (team_kills > 0)
+                .then(|| f64::from(self.kills_player + self.assists) / f64::from(team_kills)),
+            damage_share: (team_damage > 0)
+                .then(|| f64::from(self.damage_player) / team_damage as f64),
+            ..self.performance()
+        }
+    }
+
+    /// Returns `(kills + assists) / deaths`, with deaths counted as at least 1.
+    #[must_use]
+    pub fn kda(&self) -> f64 {
+        f64::from(self.kills_player + self.assists) / f64::from(self.deaths.max(1))
+    }
+
+    /// Returns the time spent in the match in minutes.
+    #[must_use]
+    pub fn minutes_played(&self) -> f64 {
+        if self.time_in_match_seconds > 0 {
+            f64::from(self.time_in_match_seconds) / 60.0
+        } else {
+            f64::from(self.minutes)
+        }
+    }
+}
+
+impl Match {
+    /// Computes the performance of every player of the match relative to their team.
+    #[must_use]
+    pub fn performances(&self) -> Vec<(&PlayerGameInfo, PerformanceSummary)> {
+        self.teams
+            .iter()
+            .flat_map(|team| {
+                team.players
+                    .iter()
+                    .map(move |player| (player, player.performance_in(team)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::game::match_details::Match;
+    use crate::entities::ids::PlayerId;
+    use crate::test_support::{game, row};
+
+    #[test]
+    fn counts_at_least_one_death_in_kda() {
+        let mut player = row(1, 1, 1, "Mid", true);
+        assert!((player.kda() - 8.0).abs() < f64::EPSILON);
+
+        player.deaths = 0;
+        assert!((player.kda() - 16.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn computes_per_minute_stats_from_time_in_match() {
+        let mut player = row(1, 1, 1, "Mid", true);
+        player.time_in_match_seconds = 1200;
+        player.time_dead_seconds = 300;
+        player.damage_player = 40_000;
+        player.gold_earned = 10_000;
+        player.damage_mitigated = 2_000;
+        player.healing = 0;
+
+        let summary = player.performance();
+        assert!((summary.damage_per_minute - 2000.0).abs() < f64::EPSILON);
+        assert!((summary.gold_per_minute - 500.0).abs() < f64::EPSILON);
+        assert!((summary.mitigated_per_minute - 100.0).abs() < f64::EPSILON);
+        assert!(summary.healing_per_minute.abs() < f64::EPSILON);
+        assert!((summary.time_dead - 0.25).abs() < f64::EPSILON);
+        assert_eq!(summary.kill_participation, None);
+        assert_eq!(summary.damage_share, None);
+    }
+
+    #[test]
+    fn falls_back_to_minutes_without_time_in_match() {
+        let mut player = row(1, 1, 1, "Mid", true);
+        player.time_in_match_seconds = 0;
+        player.minutes = 20;
+        player.gold_earned = 10_000;
+        player.time_dead_seconds = 300;
+
+        let summary = player.performance();
+        assert!((player.minutes_played() - 20.0).abs() < f64::EPSILON);
+        assert!((summary.gold_per_minute - 500.0).abs() < f64::EPSILON);
+        assert!(summary.time_dead.abs() < f64::EPSILON);
+
+        player.minutes = 0;
+        assert!(player.performance().gold_per_minute.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn caps_time_dead_at_the_whole_match() {
+        let mut player = row(1, 1, 1, "Mid", true);
+        player.time_in_match_seconds = 600;
+        player.time_dead_seconds = 900;
+
+        assert!((player.performance().time_dead - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn computes_shares_relative_to_the_team() {
+        let mut rows = game(1, &[(1, "Mid"), (2, "Jungle")], &[(3, "Mid")], true);
+        rows[0].assists = 1;
+        rows[0].damage_player = 30_000;
+        rows[1].damage_player = 10_000;
+        rows[1].kills_player = 3;
+        rows[2].kills_player = 0;
+        rows[2].damage_player = 0;
+        let game = Match::from_players(rows).unwrap();
+
+        let performances = game.performances();
+        assert_eq!(performances.len(), 3);
+
+        let (_, mid) = &performances[0];
+        assert_eq!(mid.kill_participation, Some(0.8));
+        assert_eq!(mid.damage_share, Some(0.75));
+
+        let (_, loser) = &performances[2];
+        assert_eq!(loser.kill_participation, None);
+        assert_eq!(loser.damage_share, None);
+    }
+
+    #[test]
+    fn includes_hidden_players_in_match_performances() {
+        let mut rows = game(1, &[(1, "Mid"), (2, "Jungle")], &[(3, "Mid")], true);
+        rows[1].player_id = PlayerId(0);
+        rows[1].kills_player = 3;
+        rows[1].assists = 0;
+        let game = Match::from_players(rows).unwrap();
+
+        let performances = game.performances();
+        let (hidden, summary) = performances
+            .iter()
+            .find(|(player, _)| player.player_id.is_hidden())
+            .unwrap();
+        assert_eq!(hidden.god_id.0, 2);
+        assert_eq!(summary.kill_participation, Some(0.3));
+    }
+
+    #[test]
+    fn has_no_shares_for_an_empty_team_result() {
+        let mut player = row(1, 1, 1, "Mid", false);
+        player.kills_player = 0;
+        player.assists = 0;
+        player.deaths = 0;
+        player.damage_player = 0;
+        let game = Match::from_players(vec![player, row(1, 2, 2, "Mid", true)]).unwrap();
+
+        let (_, summary) = &game.performances()[0];
+        assert!(summary.kda.abs() < f64::EPSILON);
+        assert_eq!(summary.kill_participation, None);
+        assert_eq!(summary.damage_share, None);
+    }
+}
@@ -1,295 +1,462 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
+use crate::entities::ids::{GodId, ItemId, MatchId, PartyId, PlayerId, SkinId, TeamId};
 use crate::entities::player::Merged;
 
-#[derive(Deserialize, Debug)]
+use super::match_details::Ban;
+use super::Queue;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct PlayerGameInfo {
-    #[serde(rename = "Account_Level")]
+    #[serde(rename(deserialize = "Account_Level"), alias = "account_level")]
     pub account_level: u32,
-    #[serde(rename = "ActiveId1")]
-    pub active_id1: u32,
-    #[serde(rename = "ActiveId2")]
-    pub active_id2: u32,
-    #[serde(rename = "ActiveId3")]
-    pub active_id3: u32,
-    #[serde(rename = "ActiveId4")]
-    pub active_id4: u32,
-    #[serde(rename = "ActivePlayerId")]
-    pub active_player_id: String,
-    #[serde(rename = "Assists")]
+    #[serde(rename(deserialize = "ActiveId1"), alias = "active_id1")]
+    pub active_id1: ItemId,
+    #[serde(rename(deserialize = "ActiveId2"), alias = "active_id2")]
+    pub active_id2: ItemId,
+    #[serde(rename(deserialize = "ActiveId3"), alias = "active_id3")]
+    pub active_id3: ItemId,
+    #[serde(rename(deserialize = "ActiveId4"), alias = "active_id4")]
+    pub active_id4: ItemId,
+    /// `PlayerId(0)` for bots and hidden profiles, see `PlayerId::is_hidden`.
+    #[serde(
+        rename(deserialize = "ActivePlayerId"),
+        default,
+        deserialize_with = "crate::utils::deserialize_or_default",
+        alias = "active_player_id"
+    )]
+    pub active_player_id: PlayerId,
+    #[serde(rename(deserialize = "Assists"), alias = "assists")]
     pub assists: u32,
-    #[serde(rename = "Ban1")]
+    #[serde(rename(deserialize = "Ban1"), alias = "ban1")]
     pub ban1: String,
-    #[serde(rename = "Ban10")]
+    #[serde(rename(deserialize = "Ban10"), alias = "ban10")]
     pub ban10: String,
-    #[serde(rename = "Ban10Id")]
-    pub ban10_id: u32,
-    #[serde(rename = "Ban11")]
+    #[serde(rename(deserialize = "Ban10Id"), alias = "ban10_id")]
+    pub ban10_id: GodId,
+    #[serde(rename(deserialize = "Ban11"), alias = "ban11")]
     pub ban11: String,
-    #[serde(rename = "Ban11Id")]
-    pub ban11_id: u32,
-    #[serde(rename = "Ban12")]
+    #[serde(rename(deserialize = "Ban11Id"), alias = "ban11_id")]
+    pub ban11_id: GodId,
+    #[serde(rename(deserialize = "Ban12"), alias = "ban12")]
     pub ban12: String,
-    #[serde(rename = "Ban12Id")]
-    pub ban12_id: u32,
-    #[serde(rename = "Ban1Id")]
-    pub ban1_id: u32,
-    #[serde(rename = "Ban2")]
+    #[serde(rename(deserialize = "Ban12Id"), alias = "ban12_id")]
+    pub ban12_id: GodId,
+    #[serde(rename(deserialize = "Ban1Id"), alias = "ban1_id")]
+    pub ban1_id: GodId,
+    #[serde(rename(deserialize = "Ban2"), alias = "ban2")]
     pub ban2: String,
-    #[serde(rename = "Ban2Id")]
-    pub ban2_id: u32,
-    #[serde(rename = "Ban3")]
+    #[serde(rename(deserialize = "Ban2Id"), alias = "ban2_id")]
+    pub ban2_id: GodId,
+    #[serde(rename(deserialize = "Ban3"), alias = "ban3")]
     pub ban3: String,
-    #[serde(rename = "Ban3Id")]
-    pub ban3_id: u32,
-    #[serde(rename = "Ban4")]
+    #[serde(rename(deserialize = "Ban3Id"), alias = "ban3_id")]
+    pub ban3_id: GodId,
+    #[serde(rename(deserialize = "Ban4"), alias = "ban4")]
     pub ban4: String,
-    #[serde(rename = "Ban4Id")]
-    pub ban4_id: u32,
-    #[serde(rename = "Ban5")]
+    #[serde(rename(deserialize = "Ban4Id"), alias = "ban4_id")]
+    pub ban4_id: GodId,
+    #[serde(rename(deserialize = "Ban5"), alias = "ban5")]
     pub ban5: String,
-    #[serde(rename = "Ban5Id")]
-    pub ban5_id: u32,
-    #[serde(rename = "Ban6")]
+    #[serde(rename(deserialize = "Ban5Id"), alias = "ban5_id")]
+    pub ban5_id: GodId,
+    #[serde(rename(deserialize = "Ban6"), alias = "ban6")]
     pub ban6: String,
-    #[serde(rename = "Ban6Id")]
-    pub ban6_id: u32,
-    #[serde(rename = "Ban7")]
+    #[serde(rename(deserialize = "Ban6Id"), alias = "ban6_id")]
+    pub ban6_id: GodId,
+    #[serde(rename(deserialize = "Ban7"), alias = "ban7")]
     pub ban7: String,
-    #[serde(rename = "Ban7Id")]
-    pub ban7_id: u32,
-    #[serde(rename = "Ban8")]
+    #[serde(rename(deserialize = "Ban7Id"), alias = "ban7_id")]
+    pub ban7_id: GodId,
+    #[serde(rename(deserialize = "Ban8"), alias = "ban8")]
     pub ban8: String,
-    #[serde(rename = "Ban8Id")]
-    pub ban8_id: u32,
-    #[serde(rename = "Ban9")]
+    #[serde(rename(deserialize = "Ban8Id"), alias = "ban8_id")]
+    pub ban8_id: GodId,
+    #[serde(rename(deserialize = "Ban9"), alias = "ban9")]
     pub ban9: String,
-    #[serde(rename = "Ban9Id")]
-    pub ban9_id: u32,
-    #[serde(rename = "Camps_Cleared")]
+    #[serde(rename(deserialize = "Ban9Id"), alias = "ban9_id")]
+    pub ban9_id: GodId,
+    #[serde(rename(deserialize = "Camps_Cleared"), alias = "camps_cleared")]
     pub camps_cleared: u32,
-    #[serde(rename = "Conquest_Losses")]
+    #[serde(rename(deserialize = "Conquest_Losses"), alias = "conquest_losses")]
     pub conquest_losses: u32,
-    #[serde(rename = "Conquest_Points")]
+    #[serde(rename(deserialize = "Conquest_Points"), alias = "conquest_points")]
     pub conquest_points: u32,
-    #[serde(rename = "Conquest_Tier")]
+    #[serde(rename(deserialize = "Conquest_Tier"), alias = "conquest_tier")]
     pub conquest_tier: u32,
-    #[serde(rename = "Conquest_Wins")]
+    #[serde(rename(deserialize = "Conquest_Wins"), alias = "conquest_wins")]
     pub conquest_wins: u32,
-    #[serde(rename = "Damage_Bot")]
+    #[serde(rename(deserialize = "Damage_Bot"), alias = "damage_bot")]
     pub damage_bot: u32,
-    #[serde(rename = "Damage_Done_In_Hand")]
+    #[serde(
+        rename(deserialize = "Damage_Done_In_Hand"),
+        alias = "damage_done_in_hand"
+    )]
     pub damage_done_in_hand: u32,
-    #[serde(rename = "Damage_Done_Magical")]
+    #[serde(
+        rename(deserialize = "Damage_Done_Magical"),
+        alias = "damage_done_magical"
+    )]
     pub damage_done_magical: u32,
-    #[serde(rename = "Damage_Done_Physical")]
+    #[serde(
+        rename(deserialize = "Damage_Done_Physical"),
+        alias = "damage_done_physical"
+    )]
     pub damage_done_physical: u32,
-    #[serde(rename = "Damage_Mitigated")]
+    #[serde(rename(deserialize = "Damage_Mitigated"), alias = "damage_mitigated")]
     pub damage_mitigated: u32,
-    #[serde(rename = "Damage_Player")]
+    #[serde(rename(deserialize = "Damage_Player"), alias = "damage_player")]
     pub damage_player: u32,
-    #[serde(rename = "Damage_Taken")]
+    #[serde(rename(deserialize = "Damage_Taken"), alias = "damage_taken")]
     pub damage_taken: u32,
-    #[serde(rename = "Damage_Taken_Magical")]
+    #[serde(
+        rename(deserialize = "Damage_Taken_Magical"),
+        alias = "damage_taken_magical"
+    )]
     pub damage_taken_magical: u32,
-    #[serde(rename = "Damage_Taken_Physical")]
+    #[serde(
+        rename(deserialize = "Damage_Taken_Physical"),
+        alias = "damage_taken_physical"
+    )]
     pub damage_taken_physical: u32,
-    #[serde(rename = "Deaths")]
+    #[serde(rename(deserialize = "Deaths"), alias = "deaths")]
     pub deaths: u32,
-    #[serde(rename = "Distance_Traveled")]
+    #[serde(rename(deserialize = "Distance_Traveled"), alias = "distance_traveled")]
     pub distance_traveled: u32,
-    #[serde(rename = "Duel_Losses")]
+    #[serde(rename(deserialize = "Duel_Losses"), alias = "duel_losses")]
     pub duel_losses: u32,
-    #[serde(rename = "Duel_Points")]
+    #[serde(rename(deserialize = "Duel_Points"), alias = "duel_points")]
     pub duel_points: u32,
-    #[serde(rename = "Duel_Tier")]
+    #[serde(rename(deserialize = "Duel_Tier"), alias = "duel_tier")]
     pub duel_tier: u32,
-    #[serde(rename = "Duel_Wins")]
+    #[serde(rename(deserialize = "Duel_Wins"), alias = "duel_wins")]
     pub duel_wins: u32,
-    #[serde(rename = "Entry_Datetime")]
+    #[serde(rename(deserialize = "Entry_Datetime"), alias = "entry_datetime")]
     pub entry_datetime: String,
-    #[serde(rename = "Final_Match_Level")]
+    #[serde(rename(deserialize = "Final_Match_Level"), alias = "final_match_level")]
     pub final_match_level: u32,
-    #[serde(rename = "First_Ban_Side")]
+    #[serde(rename(deserialize = "First_Ban_Side"), alias = "first_ban_side")]
     pub first_ban_side: String,
-    #[serde(rename = "GodId")]
-    pub god_id: u32,
-    #[serde(rename = "Gold_Earned")]
+    #[serde(rename(deserialize = "GodId"), alias = "god_id")]
+    pub god_id: GodId,
+    #[serde(rename(deserialize = "Gold_Earned"), alias = "gold_earned")]
     pub gold_earned: u32,
-    #[serde(rename = "Gold_Per_Minute")]
+    #[serde(rename(deserialize = "Gold_Per_Minute"), alias = "gold_per_minute")]
     pub gold_per_minute: u32,
-    #[serde(rename = "Healing")]
+    #[serde(rename(deserialize = "Healing"), alias = "healing")]
     pub healing: u32,
-    #[serde(rename = "Healing_Bot")]
+    #[serde(rename(deserialize = "Healing_Bot"), alias = "healing_bot")]
     pub healing_bot: u32,
-    #[serde(rename = "Healing_Player_Self")]
+    #[serde(
+        rename(deserialize = "Healing_Player_Self"),
+        alias = "healing_player_self"
+    )]
     pub healing_player_self: u32,
-    #[serde(rename = "ItemId1")]
-    pub item_id1: u32,
-    #[serde(rename = "ItemId2")]
-    pub item_id2: u32,
-    #[serde(rename = "ItemId3")]
-    pub item_id3: u32,
-    #[serde(rename = "ItemId4")]
-    pub item_id4: u32,
-    #[serde(rename = "ItemId5")]
-    pub item_id5: u32,
-    #[serde(rename = "ItemId6")]
-    pub item_id6: u32,
-    #[serde(rename = "Item_Active_1")]
+    #[serde(rename(deserialize = "ItemId1"), alias = "item_id1")]
+    pub item_id1: ItemId,
+    #[serde(rename(deserialize = "ItemId2"), alias = "item_id2")]
+    pub item_id2: ItemId,
+    #[serde(rename(deserialize = "ItemId3"), alias = "item_id3")]
+    pub item_id3: ItemId,
+    #[serde(rename(deserialize = "ItemId4"), alias = "item_id4")]
+    pub item_id4: ItemId,
+    #[serde(rename(deserialize = "ItemId5"), alias = "item_id5")]
+    pub item_id5: ItemId,
+    #[serde(rename(deserialize = "ItemId6"), alias = "item_id6")]
+    pub item_id6: ItemId,
+    #[serde(rename(deserialize = "Item_Active_1"), alias = "item_active_1")]
     pub item_active_1: String,
-    #[serde(rename = "Item_Active_2")]
+    #[serde(rename(deserialize = "Item_Active_2"), alias = "item_active_2")]
     pub item_active_2: String,
-    #[serde(rename = "Item_Active_3")]
+    #[serde(rename(deserialize = "Item_Active_3"), alias = "item_active_3")]
     pub item_active_3: String,
-    #[serde(rename = "Item_Active_4")]
+    #[serde(rename(deserialize = "Item_Active_4"), alias = "item_active_4")]
     pub item_active_4: String,
-    #[serde(rename = "Item_Purch_1")]
+    #[serde(rename(deserialize = "Item_Purch_1"), alias = "item_purch_1")]
     pub item_purch_1: String,
-    #[serde(rename = "Item_Purch_2")]
+    #[serde(rename(deserialize = "Item_Purch_2"), alias = "item_purch_2")]
     pub item_purch_2: String,
-    #[serde(rename = "Item_Purch_3")]
+    #[serde(rename(deserialize = "Item_Purch_3"), alias = "item_purch_3")]
     pub item_purch_3: String,
-    #[serde(rename = "Item_Purch_4")]
+    #[serde(rename(deserialize = "Item_Purch_4"), alias = "item_purch_4")]
     pub item_purch_4: String,
-    #[serde(rename = "Item_Purch_5")]
+    #[serde(rename(deserialize = "Item_Purch_5"), alias = "item_purch_5")]
     pub item_purch_5: String,
-    #[serde(rename = "Item_Purch_6")]
+    #[serde(rename(deserialize = "Item_Purch_6"), alias = "item_purch_6")]
     pub item_purch_6: String,
-    #[serde(rename = "Joust_Losses")]
+    #[serde(rename(deserialize = "Joust_Losses"), alias = "joust_losses")]
     pub joust_losses: u32,
-    #[serde(rename = "Joust_Points")]
+    #[serde(rename(deserialize = "Joust_Points"), alias = "joust_points")]
     pub joust_points: u32,
-    #[serde(rename = "Joust_Tier")]
+    #[serde(rename(deserialize = "Joust_Tier"), alias = "joust_tier")]
     pub joust_tier: u32,
-    #[serde(rename = "Joust_Wins")]
+    #[serde(rename(deserialize = "Joust_Wins"), alias = "joust_wins")]
     pub joust_wins: u32,
-    #[serde(rename = "Killing_Spree")]
+    #[serde(rename(deserialize = "Killing_Spree"), alias = "killing_spree")]
     pub killing_spree: u32,
-    #[serde(rename = "Kills_Bot")]
+    #[serde(rename(deserialize = "Kills_Bot"), alias = "kills_bot")]
     pub kills_bot: u32,
-    #[serde(rename = "Kills_Double")]
+    #[serde(rename(deserialize = "Kills_Double"), alias = "kills_double")]
     pub kills_double: u32,
-    #[serde(rename = "Kills_Fire_Giant")]
+    #[serde(rename(deserialize = "Kills_Fire_Giant"), alias = "kills_fire_giant")]
     pub kills_fire_giant: u32,
-    #[serde(rename = "Kills_First_Blood")]
+    #[serde(rename(deserialize = "Kills_First_Blood"), alias = "kills_first_blood")]
     pub kills_first_blood: u32,
-    #[serde(rename = "Kills_Gold_Fury")]
+    #[serde(rename(deserialize = "Kills_Gold_Fury"), alias = "kills_gold_fury")]
     pub kills_gold_fury: u32,
-    #[serde(rename = "Kills_Penta")]
+    #[serde(rename(deserialize = "Kills_Penta"), alias = "kills_penta")]
     pub kills_penta: u32,
-    #[serde(rename = "Kills_Phoenix")]
+    #[serde(rename(deserialize = "Kills_Phoenix"), alias = "kills_phoenix")]
     pub kills_phoenix: u32,
-    #[serde(rename = "Kills_Player")]
+    #[serde(rename(deserialize = "Kills_Player"), alias = "kills_player")]
     pub kills_player: u32,
-    #[serde(rename = "Kills_Quadra")]
+    #[serde(rename(deserialize = "Kills_Quadra"), alias = "kills_quadra")]
     pub kills_quadra: u32,
-    #[serde(rename = "Kills_Siege_Juggernaut")]
+    #[serde(
+        rename(deserialize = "Kills_Siege_Juggernaut"),
+        alias = "kills_siege_juggernaut"
+    )]
     pub kills_siege_juggernaut: u32,
-    #[serde(rename = "Kills_Single")]
+    #[serde(rename(deserialize = "Kills_Single"), alias = "kills_single")]
     pub kills_single: u32,
-    #[serde(rename = "Kills_Triple")]
+    #[serde(rename(deserialize = "Kills_Triple"), alias = "kills_triple")]
     pub kills_triple: u32,
-    #[serde(rename = "Kills_Wild_Juggernaut")]
+    #[serde(
+        rename(deserialize = "Kills_Wild_Juggernaut"),
+        alias = "kills_wild_juggernaut"
+    )]
     pub kills_wild_juggernaut: u32,
-    #[serde(rename = "Map_Game")]
+    #[serde(rename(deserialize = "Map_Game"), alias = "map_game")]
     pub map_game: String,
-    #[serde(rename = "Mastery_Level")]
+    #[serde(rename(deserialize = "Mastery_Level"), alias = "mastery_level")]
     pub mastery_level: u32,
-    #[serde(rename = "Match")]
-    pub match_id: u64,
-    #[serde(rename = "Match_Duration")]
+    #[serde(rename(deserialize = "Match"), alias = "match_id")]
+    pub match_id: MatchId,
+    #[serde(rename(deserialize = "Match_Duration"), alias = "match_duration")]
     pub match_duration: u32,
-    #[serde(rename = "MergedPlayers")]
+    #[serde(rename(deserialize = "MergedPlayers"), alias = "merged_players")]
     pub merged_players: Option<Vec<Merged>>,
-    #[serde(rename = "Minutes")]
+    #[serde(rename(deserialize = "Minutes"), alias = "minutes")]
     pub minutes: u32,
-    #[serde(rename = "Multi_kill_Max")]
+    #[serde(rename(deserialize = "Multi_kill_Max"), alias = "multi_kill_max")]
     pub multi_kill_max: u32,
-    #[serde(rename = "Objective_Assists")]
+    #[serde(rename(deserialize = "Objective_Assists"), alias = "objective_assists")]
     pub objective_assists: u32,
-    #[serde(rename = "PartyId")]
-    pub party_id: u64,
-    #[serde(rename = "Rank_Stat_Conquest")]
+    #[serde(rename(deserialize = "PartyId"), alias = "party_id")]
+    pub party_id: PartyId,
+    #[serde(
+        rename(deserialize = "Rank_Stat_Conquest"),
+        alias = "rank_stat_conquest"
+    )]
     pub rank_stat_conquest: f64,
-    #[serde(rename = "Rank_Stat_Duel")]
+    #[serde(rename(deserialize = "Rank_Stat_Duel"), alias = "rank_stat_duel")]
     pub rank_stat_duel: f64,
-    #[serde(rename = "Rank_Stat_Joust")]
+    #[serde(rename(deserialize = "Rank_Stat_Joust"), alias = "rank_stat_joust")]
     pub rank_stat_joust: f64,
-    #[serde(rename = "Reference_Name")]
+    #[serde(rename(deserialize = "Reference_Name"), alias = "reference_name")]
     pub reference_name: String,
-    #[serde(rename = "Region")]
+    #[serde(rename(deserialize = "Region"), alias = "region")]
     pub region: String,
-    #[serde(rename = "Role")]
+    #[serde(rename(deserialize = "Role"), alias = "role")]
     pub role: String,
-    #[serde(rename = "Skin")]
+    #[serde(rename(deserialize = "Skin"), alias = "skin")]
     pub skin: String,
-    #[serde(rename = "SkinId")]
-    pub skin_id: u32,
-    #[serde(rename = "Structure_Damage")]
+    #[serde(rename(deserialize = "SkinId"), alias = "skin_id")]
+    pub skin_id: SkinId,
+    #[serde(rename(deserialize = "Structure_Damage"), alias = "structure_damage")]
     pub structure_damage: u32,
-    #[serde(rename = "Surrendered")]
+    #[serde(rename(deserialize = "Surrendered"), alias = "surrendered")]
     pub surrendered: u32,
-    #[serde(rename = "TaskForce")]
+    #[serde(rename(deserialize = "TaskForce"), alias = "task_force")]
     pub task_force: u32,
-    #[serde(rename = "Team1Score")]
+    #[serde(rename(deserialize = "Team1Score"), alias = "team1_score")]
     pub team1_score: u32,
-    #[serde(rename = "Team2Score")]
+    #[serde(rename(deserialize = "Team2Score"), alias = "team2_score")]
     pub team2_score: u32,
-    #[serde(rename = "TeamId")]
-    pub team_id: u32,
-    #[serde(rename = "Team_Name")]
+    #[serde(rename(deserialize = "TeamId"), alias = "team_id")]
+    pub team_id: TeamId,
+    #[serde(rename(deserialize = "Team_Name"), alias = "team_name")]
     pub team_name: String,
-    #[serde(rename = "Time_Dead_Seconds")]
+    #[serde(rename(deserialize = "Time_Dead_Seconds"), alias = "time_dead_seconds")]
     pub time_dead_seconds: u32,
-    #[serde(rename = "Time_In_Match_Seconds")]
+    #[serde(
+        rename(deserialize = "Time_In_Match_Seconds"),
+        alias = "time_in_match_seconds"
+    )]
     pub time_in_match_seconds: u32,
-    #[serde(rename = "Towers_Destroyed")]
+    #[serde(rename(deserialize = "Towers_Destroyed"), alias = "towers_destroyed")]
     pub towers_destroyed: u32,
-    #[serde(rename = "Wards_Placed")]
+    #[serde(rename(deserialize = "Wards_Placed"), alias = "wards_placed")]
     pub wards_placed: u32,
-    #[serde(rename = "Win_Status")]
+    #[serde(rename(deserialize = "Win_Status"), alias = "win_status")]
     pub win_status: String,
-    #[serde(rename = "Winning_TaskForce")]
+    #[serde(
+        rename(deserialize = "Winning_TaskForce"),
+        alias = "winning_task_force"
+    )]
     pub winning_task_force: u32,
-    #[serde(rename = "hasReplay")]
-    pub has_replay: String,
-    #[serde(rename = "hz_gamer_tag")]
+    #[serde(
+        rename(deserialize = "hasReplay"),
+        deserialize_with = "crate::utils::bool_from_string",
+        alias = "has_replay"
+    )]
+    pub has_replay: bool,
+    #[serde(rename(deserialize = "hz_gamer_tag"))]
     pub hz_gamer_tag: Option<String>,
-    #[serde(rename = "hz_player_name")]
+    #[serde(rename(deserialize = "hz_player_name"))]
     pub hz_player_name: Option<String>,
-    #[serde(rename = "match_queue_id")]
-    pub match_queue_id: u32,
-    #[serde(rename = "name")]
+    #[serde(rename(deserialize = "match_queue_id"))]
+    pub match_queue_id: Queue,
+    #[serde(rename(deserialize = "name"))]
     pub name: String,
-    #[serde(rename = "playerId")]
-    pub player_id: String,
-    #[serde(rename = "playerName")]
+    /// `PlayerId(0)` for bots and hidden profiles, see `PlayerId::is_hidden`.
+    #[serde(
+        rename(deserialize = "playerId"),
+        default,
+        deserialize_with = "crate::utils::deserialize_or_default",
+        alias = "player_id"
+    )]
+    pub player_id: PlayerId,
+    #[serde(rename(deserialize = "playerName"), alias = "player_name")]
     pub player_name: String,
-    #[serde(rename = "playerPortalId")]
+    #[serde(rename(deserialize = "playerPortalId"), alias = "player_portal_id")]
     pub player_portal_id: Option<String>,
-    #[serde(rename = "playerPortalUserId")]
+    #[serde(
+        rename(deserialize = "playerPortalUserId"),
+        alias = "player_portal_user_id"
+    )]
     pub player_portal_user_id: Option<String>,
-    #[serde(rename = "ret_msg")]
+    #[serde(rename(deserialize = "ret_msg"))]
     pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// An item slot of a player's final build, as reported by `getmatchdetails`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemSlot<'a> {
+    pub id: ItemId,
+    pub name: &'a str,
+}
+
+impl ItemSlot<'_> {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.id.0 == 0 || self.name.trim().is_empty()
+    }
 }
 
 impl PlayerGameInfo {
+    /// Returns all six item slots (`ItemId1..6` / `Item_Purch_1..6`) in order,
+    /// including empty ones.
+    #[must_use]
+    pub fn item_slots(&self) -> [ItemSlot<'_>; 6] {
+        [
+            ItemSlot {
+                id: self.item_id1,
+                name: &self.item_purch_1,
+            },
+            ItemSlot {
+                id: self.item_id2,
+                name: &self.item_purch_2,
+            },
+            ItemSlot {
+                id: self.item_id3,
+                name: &self.item_purch_3,
+            },
+            ItemSlot {
+                id: self.item_id4,
+                name: &self.item_purch_4,
+            },
+            ItemSlot {
+                id: self.item_id5,
+                name: &self.item_purch_5,
+            },
+            ItemSlot {
+                id: self.item_id6,
+                name: &self.item_purch_6,
+            },
+        ]
+    }
+
+    /// Returns all four relic slots (`ActiveId1..4` / `Item_Active_1..4`) in order,
+    /// including empty ones.
+    #[must_use]
+    pub fn relic_slots(&self) -> [ItemSlot<'_>; 4] {
+        [
+            ItemSlot {
+                id: self.active_id1,
+                name: &self.item_active_1,
+            },
+            ItemSlot {
+                id: self.active_id2,
+                name: &self.item_active_2,
+            },
+            ItemSlot {
+                id: self.active_id3,
+                name: &self.item_active_3,
+            },
+            ItemSlot {
+                id: self.active_id4,
+                name: &self.item_active_4,
+            },
+        ]
+    }
+
+    /// Returns the non-empty item slots of the final build.
+    #[must_use]
+    pub fn items(&self) -> Vec<ItemSlot<'_>> {
+        self.item_slots()
+            .into_iter()
+            .filter(|item| !item.is_empty())
+            .collect()
+    }
+
+    /// Returns the non-empty relic (active) slots.
     #[must_use]
-    pub fn bought_items(&self) -> Vec<(&str, u32)> {
+    pub fn relics(&self) -> Vec<ItemSlot<'_>> {
+        self.relic_slots()
+            .into_iter()
+            .filter(|relic| !relic.is_empty())
+            .collect()
+    }
+
+    /// Returns the gods banned in the match (`Ban1..12` / `Ban1Id..12Id`), in ban order.
+    #[must_use]
+    pub fn bans(&self) -> Vec<Ban> {
         [
-            (self.item_purch_1.as_str(), self.item_id1),
-            (self.item_purch_2.as_str(), self.item_id2),
-            (self.item_purch_3.as_str(), self.item_id3),
-            (self.item_purch_4.as_str(), self.item_id4),
-            (self.item_purch_5.as_str(), self.item_id5),
-            (self.item_purch_6.as_str(), self.item_id6),
+            (&self.ban1, self.ban1_id),
+            (&self.ban2, self.ban2_id),
+            (&self.ban3, self.ban3_id),
+            (&self.ban4, self.ban4_id),
+            (&self.ban5, self.ban5_id),
+            (&self.ban6, self.ban6_id),
+            (&self.ban7, self.ban7_id),
+            (&self.ban8, self.ban8_id),
+            (&self.ban9, self.ban9_id),
+            (&self.ban10, self.ban10_id),
+            (&self.ban11, self.ban11_id),
+            (&self.ban12, self.ban12_id),
         ]
         .into_iter()
-        .filter(|(name, _)| !name.trim().is_empty())
+        .filter(|(_, id)| id.0 != 0)
+        .map(|(name, god_id)| Ban {
+            god_id,
+            god_name: name.clone(),
+        })
         .collect()
     }
 
+    #[must_use]
+    pub fn bought_items(&self) -> Vec<(&str, ItemId)> {
+        self.item_slots()
+            .into_iter()
+            .filter(|item| !item.name.trim().is_empty())
+            .map(|item| (item.name, item.id))
+            .collect()
+    }
+
     #[must_use]
     pub fn has_won(&self) -> bool {
         self.win_status == "Winner"
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::ids::{GodId, MatchId};
+use crate::error::Result;
+
+use super::match_details::Match;
+
+/// A match listed by `gettopmatches`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TopMatch {
+    #[serde(rename(deserialize = "Ban1"), alias = "ban1")]
+    pub ban1: Option<String>,
+    #[serde(rename(deserialize = "Ban1Id"), alias = "ban1_id")]
+    pub ban1_id: GodId,
+    #[serde(rename(deserialize = "Ban2"), alias = "ban2")]
+    pub ban2: Option<String>,
+    #[serde(rename(deserialize = "Ban2Id"), alias = "ban2_id")]
+    pub ban2_id: GodId,
+    #[serde(rename(deserialize = "Entry_Datetime"), alias = "entry_datetime")]
+    pub entry_datetime: Option<String>,
+    #[serde(rename(deserialize = "LiveSpectators"), alias = "live_spectators")]
+    pub live_spectators: u32,
+    #[serde(rename(deserialize = "Match"), alias = "match_id")]
+    pub match_id: MatchId,
+    #[serde(rename(deserialize = "Match_Time"), alias = "match_time")]
+    pub match_time: u32,
+    #[serde(
+        rename(deserialize = "OfflineSpectators"),
+        alias = "offline_spectators"
+    )]
+    pub offline_spectators: u32,
+    #[serde(rename(deserialize = "Queue"), alias = "queue")]
+    pub queue: String,
+    #[serde(
+        rename(deserialize = "RecordingFinished"),
+        alias = "recording_finished"
+    )]
+    pub recording_finished: Option<String>,
+    #[serde(rename(deserialize = "RecordingStarted"), alias = "recording_started")]
+    pub recording_started: Option<String>,
+    #[serde(rename(deserialize = "Team1_AvgLevel"), alias = "team1_avg_level")]
+    pub team1_avg_level: u32,
+    #[serde(rename(deserialize = "Team1_Gold"), alias = "team1_gold")]
+    pub team1_gold: u32,
+    #[serde(rename(deserialize = "Team1_Kills"), alias = "team1_kills")]
+    pub team1_kills: u32,
+    #[serde(rename(deserialize = "Team1_Score"), alias = "team1_score")]
+    pub team1_score: u32,
+    #[serde(rename(deserialize = "Team2_AvgLevel"), alias = "team2_avg_level")]
+    pub team2_avg_level: u32,
+    #[serde(rename(deserialize = "Team2_Gold"), alias = "team2_gold")]
+    pub team2_gold: u32,
+    #[serde(rename(deserialize = "Team2_Kills"), alias = "team2_kills")]
+    pub team2_kills: u32,
+    #[serde(rename(deserialize = "Team2_Score"), alias = "team2_score")]
+    pub team2_score: u32,
+    #[serde(rename(deserialize = "WinningTeam"), alias = "winning_team")]
+    pub winning_team: u32,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl Client {
+    /// Returns the 50 most watched or most recent recorded matches.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_top_matches(&self) -> Result<Vec<TopMatch>> {
+        self.make_request("gettopmatches", true, &[]).await
+    }
+
+    /// Retrieves full details of the given top matches with `get_match_details_batch`.
+    ///
+    /// # Errors
+    /// - If any of the API requests fails.
+    pub async fn expand_top_matches(&self, top_matches: &[TopMatch]) -> Result<Vec<Match>> {
+        let match_ids = top_matches
+            .iter()
+            .map(|top_match| top_match.match_id)
+            .collect();
+        let players = self.get_match_details_batch(match_ids).await?;
+
+        Ok(Match::group(players))
+    }
+}
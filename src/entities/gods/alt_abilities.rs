@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::cache::CacheCategory;
+use crate::client::Client;
+use crate::entities::ids::{GodId, ItemId};
+use crate::error::Result;
+
+use super::{God, GodAbility};
+
+/// An alternate ability available to a god in modes like Arena Assault or Odyssey.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AltAbility {
+    #[serde(rename(deserialize = "alt_name"))]
+    pub alt_name: String,
+    /// Slot of the replaced ability, e.g. `"Ability 2"`.
+    #[serde(rename(deserialize = "alt_position"))]
+    pub alt_position: String,
+    #[serde(rename(deserialize = "god_id"))]
+    pub god_id: GodId,
+    #[serde(rename(deserialize = "god_name"))]
+    pub god_name: String,
+    #[serde(rename(deserialize = "item_id"))]
+    pub item_id: ItemId,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl AltAbility {
+    /// Returns the slot (1 to 5) of the ability replaced by this one.
+    #[must_use]
+    pub fn slot(&self) -> Option<u8> {
+        self.alt_position
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+
+    /// Finds the god this alternate ability belongs to.
+    #[must_use]
+    pub fn god<'a>(&self, gods: &'a [God]) -> Option<&'a God> {
+        gods.iter().find(|god| god.id == self.god_id)
+    }
+
+    /// Returns the ability of `god` replaced by this one.
+    #[must_use]
+    pub fn replaced_ability<'a>(&self, god: &'a God) -> Option<GodAbility<'a>> {
+        if god.id != self.god_id {
+            return None;
+        }
+
+        let slot = self.slot()?;
+        god.abilities()
+            .into_iter()
+            .find(|ability| ability.slot == slot)
+    }
+}
+
+impl God {
+    /// Returns the alternate abilities of this god.
+    #[must_use]
+    pub fn alt_abilities<'a>(&self, alt_abilities: &'a [AltAbility]) -> Vec<&'a AltAbility> {
+        alt_abilities
+            .iter()
+            .filter(|alt| alt.god_id == self.id)
+            .collect()
+    }
+}
+
+impl Client {
+    /// Retrieves alternate abilities of all gods.
+    ///
+    /// # Errors
+    ///
+    /// - If the API request fails.
+    pub async fn get_god_alt_abilities(&self) -> Result<Vec<AltAbility>> {
+        self.make_cached_request(CacheCategory::Gods, "getgodaltabilities", &[])
+            .await
+    }
+}
@@ -0,0 +1,247 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Class of a god, as reported in `God::roles`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Role {
+    Assassin,
+    Guardian,
+    Hunter,
+    Mage,
+    Warrior,
+    Other(String),
+}
+
+/// Pantheon a god belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Pantheon {
+    Arthurian,
+    Babylonian,
+    Celtic,
+    Chinese,
+    Egyptian,
+    GreatOldOnes,
+    Greek,
+    Hindu,
+    Japanese,
+    Korean,
+    Maya,
+    Norse,
+    Polynesian,
+    Roman,
+    Slavic,
+    Voodoo,
+    Yoruba,
+    Other(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum AttackRange {
+    Melee,
+    Ranged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum DamageType {
+    Physical,
+    Magical,
+}
+
+/// Attack range and damage type of a god.
+/// The API reports it in `God::type` as a single string, e.g. `"Ranged, Magical"`,
+/// which is also how it is serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GodType {
+    pub range: Option<AttackRange>,
+    pub damage: Option<DamageType>,
+}
+
+impl FromStr for Role {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let role = match s.trim().to_lowercase().as_str() {
+            "assassin" => Role::Assassin,
+            "guardian" => Role::Guardian,
+            "hunter" => Role::Hunter,
+            "mage" => Role::Mage,
+            "warrior" => Role::Warrior,
+            _ => Role::Other(s.trim().to_string()),
+        };
+
+        Ok(role)
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Role::Assassin => "Assassin",
+            Role::Guardian => "Guardian",
+            Role::Hunter => "Hunter",
+            Role::Mage => "Mage",
+            Role::Warrior => "Warrior",
+            Role::Other(name) => name,
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Pantheon {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pantheon = match s.trim().to_lowercase().as_str() {
+            "arthurian" => Pantheon::Arthurian,
+            "babylonian" => Pantheon::Babylonian,
+            "celtic" => Pantheon::Celtic,
+            "chinese" => Pantheon::Chinese,
+            "egyptian" => Pantheon::Egyptian,
+            "great old ones" => Pantheon::GreatOldOnes,
+            "greek" => Pantheon::Greek,
+            "hindu" => Pantheon::Hindu,
+            "japanese" => Pantheon::Japanese,
+            "korean" => Pantheon::Korean,
+            "maya" | "mayan" => Pantheon::Maya,
+            "norse" => Pantheon::Norse,
+            "polynesian" => Pantheon::Polynesian,
+            "roman" => Pantheon::Roman,
+            "slavic" => Pantheon::Slavic,
+            "voodoo" => Pantheon::Voodoo,
+            "yoruba" => Pantheon::Yoruba,
+            _ => Pantheon::Other(s.trim().to_string()),
+        };
+
+        Ok(pantheon)
+    }
+}
+
+impl fmt::Display for Pantheon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Pantheon::Arthurian => "Arthurian",
+            Pantheon::Babylonian => "Babylonian",
+            Pantheon::Celtic => "Celtic",
+            Pantheon::Chinese => "Chinese",
+            Pantheon::Egyptian => "Egyptian",
+            Pantheon::GreatOldOnes => "Great Old Ones",
+            Pantheon::Greek => "Greek",
+            Pantheon::Hindu => "Hindu",
+            Pantheon::Japanese => "Japanese",
+            Pantheon::Korean => "Korean",
+            Pantheon::Maya => "Maya",
+            Pantheon::Norse => "Norse",
+            Pantheon::Polynesian => "Polynesian",
+            Pantheon::Roman => "Roman",
+            Pantheon::Slavic => "Slavic",
+            Pantheon::Voodoo => "Voodoo",
+            Pantheon::Yoruba => "Yoruba",
+            Pantheon::Other(name) => name,
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl FromStr for GodType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut god_type = GodType {
+            range: None,
+            damage: None,
+        };
+
+        for part in s.split(',').map(|part| part.trim().to_lowercase()) {
+            match part.as_str() {
+                "melee" => god_type.range = Some(AttackRange::Melee),
+                "ranged" => god_type.range = Some(AttackRange::Ranged),
+                "physical" => god_type.damage = Some(DamageType::Physical),
+                "magical" => god_type.damage = Some(DamageType::Magical),
+                _ => {}
+            }
+        }
+
+        Ok(god_type)
+    }
+}
+
+impl fmt::Display for GodType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range = self.range.map(|range| match range {
+            AttackRange::Melee => "Melee",
+            AttackRange::Ranged => "Ranged",
+        });
+        let damage = self.damage.map(|damage| match damage {
+            DamageType::Physical => "Physical",
+            DamageType::Magical => "Magical",
+        });
+        let parts: Vec<&str> = range.into_iter().chain(damage).collect();
+
+        f.write_str(&parts.join(", "))
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|never| match never {})
+    }
+}
+
+impl<'de> Deserialize<'de> for Pantheon {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|never| match never {})
+    }
+}
+
+impl<'de> Deserialize<'de> for GodType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|never| match never {})
+    }
+}
+
+impl Serialize for Role {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for Pantheon {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for GodType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
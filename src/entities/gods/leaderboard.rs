@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::game::Queue;
+use crate::entities::ids::{GodId, PlayerId};
+use crate::error::{Error, Result};
+
+/// A worshipper of a god on the god's leaderboard.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct GodLeaderboardEntry {
+    #[serde(rename(deserialize = "god_level"))]
+    pub god_level: u32,
+    #[serde(rename(deserialize = "losses"))]
+    pub losses: u32,
+    #[serde(rename(deserialize = "player_id"))]
+    pub player_id: PlayerId,
+    #[serde(rename(deserialize = "player_name"))]
+    pub player_name: String,
+    #[serde(rename(deserialize = "player_ranking"))]
+    pub player_ranking: String,
+    #[serde(rename(deserialize = "rank"))]
+    pub rank: u32,
+    #[serde(rename(deserialize = "wins"))]
+    pub wins: u32,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl Client {
+    /// Retrieves the top players of a god in the given queue.
+    /// Only ranked queues are supported: `RankedConquest`, `RankedJoust` and `RankedDuel`.
+    ///
+    /// # Errors
+    ///
+    /// - If the `queue` is not a ranked queue.
+    /// - If the API request fails.
+    pub async fn get_god_leaderboard(
+        &self,
+        god_id: GodId,
+        queue: Queue,
+    ) -> Result<Vec<GodLeaderboardEntry>> {
+        if !queue.is_ranked() {
+            return Err(Error::InvalidArgument {
+                given: format!("{queue:?}"),
+                expected: "ranked queue".to_string(),
+            });
+        }
+
+        self.make_request(
+            "getgodleaderboard",
+            true,
+            &[&god_id.to_string(), &queue.to_id().to_string()],
+        )
+        .await
+    }
+}
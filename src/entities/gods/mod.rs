@@ -0,0 +1,327 @@
+pub mod alt_abilities;
+pub mod attributes;
+pub mod leaderboard;
+pub mod recommended_items;
+pub mod skins;
+pub mod stats;
+pub mod tooltip;
+
+use crate::cache::CacheCategory;
+use crate::client::Client;
+use crate::entities::ids::{AbilityId, GodId};
+use crate::entities::language::Language;
+use crate::error::Result;
+
+use attributes::{GodType, Pantheon, Role};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AbilityDescription {
+    pub cooldown: Option<String>,
+    pub cost: Option<String>,
+    pub description: Option<String>,
+    #[serde(default = "Vec::new")]
+    pub menuitems: Vec<Menuitem>,
+    #[serde(default = "Vec::new")]
+    pub rankitems: Vec<Rankitem>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Menuitem {
+    pub description: String,
+    pub value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Rankitem {
+    pub description: String,
+    pub value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Ability {
+    #[serde(rename(deserialize = "Description"), alias = "description")]
+    pub description: BasicAttack,
+    #[serde(rename(deserialize = "Id"), alias = "id")]
+    pub id: AbilityId,
+    #[serde(rename(deserialize = "Summary"), alias = "summary")]
+    pub summary: String,
+    #[serde(rename(deserialize = "URL"), alias = "url")]
+    pub url: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct BasicAttack {
+    #[serde(rename(deserialize = "itemDescription"), alias = "item_description")]
+    pub item_description: ItemDescription,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ItemDescription {
+    pub cooldown: Option<String>,
+    pub cost: Option<String>,
+    pub description: Option<String>,
+    #[serde(default = "Vec::new")]
+    pub menuitems: Vec<Menuitem>,
+    #[serde(default = "Vec::new")]
+    pub rankitems: Vec<Rankitem>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct God {
+    #[serde(rename(deserialize = "Ability1"), alias = "ability1")]
+    pub ability1: String,
+    #[serde(rename(deserialize = "Ability2"), alias = "ability2")]
+    pub ability2: String,
+    #[serde(rename(deserialize = "Ability3"), alias = "ability3")]
+    pub ability3: String,
+    #[serde(rename(deserialize = "Ability4"), alias = "ability4")]
+    pub ability4: String,
+    #[serde(rename(deserialize = "Ability5"), alias = "ability5")]
+    pub ability5: String,
+    #[serde(rename(deserialize = "AbilityId1"), alias = "ability_id1")]
+    pub ability_id1: AbilityId,
+    #[serde(rename(deserialize = "AbilityId2"), alias = "ability_id2")]
+    pub ability_id2: AbilityId,
+    #[serde(rename(deserialize = "AbilityId3"), alias = "ability_id3")]
+    pub ability_id3: AbilityId,
+    #[serde(rename(deserialize = "AbilityId4"), alias = "ability_id4")]
+    pub ability_id4: AbilityId,
+    #[serde(rename(deserialize = "AbilityId5"), alias = "ability_id5")]
+    pub ability_id5: AbilityId,
+    #[serde(rename(deserialize = "Ability_1"), alias = "ability_1")]
+    pub ability_1: Ability,
+    #[serde(rename(deserialize = "Ability_2"), alias = "ability_2")]
+    pub ability_2: Ability,
+    #[serde(rename(deserialize = "Ability_3"), alias = "ability_3")]
+    pub ability_3: Ability,
+    #[serde(rename(deserialize = "Ability_4"), alias = "ability_4")]
+    pub ability_4: Ability,
+    #[serde(rename(deserialize = "Ability_5"), alias = "ability_5")]
+    pub ability_5: Ability,
+    #[serde(rename(deserialize = "AttackSpeed"), alias = "attack_speed")]
+    pub attack_speed: f32,
+    #[serde(
+        rename(deserialize = "AttackSpeedPerLevel"),
+        alias = "attack_speed_per_level"
+    )]
+    pub attack_speed_per_level: f32,
+    #[serde(
+        rename(deserialize = "AutoBanned"),
+        deserialize_with = "crate::utils::bool_from_string",
+        alias = "auto_banned"
+    )]
+    pub auto_banned: bool,
+    #[serde(rename(deserialize = "Cons"), alias = "cons")]
+    pub cons: String,
+    #[serde(rename(deserialize = "HP5PerLevel"), alias = "hp5_per_level")]
+    pub hp5_per_level: f32,
+    #[serde(rename(deserialize = "Health"), alias = "health")]
+    pub health: f32,
+    #[serde(rename(deserialize = "HealthPerFive"), alias = "health_per_five")]
+    pub health_per_five: f32,
+    #[serde(rename(deserialize = "HealthPerLevel"), alias = "health_per_level")]
+    pub health_per_level: f32,
+    #[serde(rename(deserialize = "Lore"), alias = "lore")]
+    pub lore: String,
+    #[serde(rename(deserialize = "MP5PerLevel"), alias = "mp5_per_level")]
+    pub mp5_per_level: f32,
+    #[serde(rename(deserialize = "MagicProtection"), alias = "magic_protection")]
+    pub magic_protection: f32,
+    #[serde(
+        rename(deserialize = "MagicProtectionPerLevel"),
+        alias = "magic_protection_per_level"
+    )]
+    pub magic_protection_per_level: f32,
+    #[serde(rename(deserialize = "MagicalPower"), alias = "magical_power")]
+    pub magical_power: f32,
+    #[serde(
+        rename(deserialize = "MagicalPowerPerLevel"),
+        alias = "magical_power_per_level"
+    )]
+    pub magical_power_per_level: f32,
+    #[serde(rename(deserialize = "Mana"), alias = "mana")]
+    pub mana: f32,
+    #[serde(rename(deserialize = "ManaPerFive"), alias = "mana_per_five")]
+    pub mana_per_five: f32,
+    #[serde(rename(deserialize = "ManaPerLevel"), alias = "mana_per_level")]
+    pub mana_per_level: f32,
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    pub name: String,
+    #[serde(
+        rename(deserialize = "OnFreeRotation"),
+        deserialize_with = "crate::utils::bool_from_string",
+        alias = "on_free_rotation"
+    )]
+    pub on_free_rotation: bool,
+    #[serde(rename(deserialize = "Pantheon"), alias = "pantheon")]
+    pub pantheon: Pantheon,
+    #[serde(rename(deserialize = "PhysicalPower"), alias = "physical_power")]
+    pub physical_power: f32,
+    #[serde(
+        rename(deserialize = "PhysicalPowerPerLevel"),
+        alias = "physical_power_per_level"
+    )]
+    pub physical_power_per_level: f32,
+    #[serde(
+        rename(deserialize = "PhysicalProtection"),
+        alias = "physical_protection"
+    )]
+    pub physical_protection: f32,
+    #[serde(
+        rename(deserialize = "PhysicalProtectionPerLevel"),
+        alias = "physical_protection_per_level"
+    )]
+    pub physical_protection_per_level: f32,
+    #[serde(rename(deserialize = "Pros"), alias = "pros")]
+    pub pros: String,
+    #[serde(rename(deserialize = "Roles"), alias = "roles")]
+    pub roles: Role,
+    #[serde(rename(deserialize = "Speed"), alias = "speed")]
+    pub speed: f32,
+    #[serde(rename(deserialize = "Title"), alias = "title")]
+    pub title: String,
+    #[serde(rename(deserialize = "Type"), alias = "type")]
+    pub r#type: GodType,
+    #[serde(
+        rename(deserialize = "abilityDescription1"),
+        alias = "ability_description1"
+    )]
+    pub ability_description1: AbilityDescription,
+    #[serde(
+        rename(deserialize = "abilityDescription2"),
+        alias = "ability_description2"
+    )]
+    pub ability_description2: AbilityDescription,
+    #[serde(
+        rename(deserialize = "abilityDescription3"),
+        alias = "ability_description3"
+    )]
+    pub ability_description3: AbilityDescription,
+    #[serde(
+        rename(deserialize = "abilityDescription4"),
+        alias = "ability_description4"
+    )]
+    pub ability_description4: AbilityDescription,
+    #[serde(
+        rename(deserialize = "abilityDescription5"),
+        alias = "ability_description5"
+    )]
+    pub ability_description5: AbilityDescription,
+    #[serde(rename(deserialize = "basicAttack"), alias = "basic_attack")]
+    pub basic_attack: BasicAttack,
+    #[serde(rename(deserialize = "godAbility1_URL"), alias = "god_ability1_url")]
+    pub god_ability1_url: String,
+    #[serde(rename(deserialize = "godAbility2_URL"), alias = "god_ability2_url")]
+    pub god_ability2_url: String,
+    #[serde(rename(deserialize = "godAbility3_URL"), alias = "god_ability3_url")]
+    pub god_ability3_url: String,
+    #[serde(rename(deserialize = "godAbility4_URL"), alias = "god_ability4_url")]
+    pub god_ability4_url: String,
+    #[serde(rename(deserialize = "godAbility5_URL"), alias = "god_ability5_url")]
+    pub god_ability5_url: String,
+    #[serde(rename(deserialize = "godCard_URL"), alias = "god_card_url")]
+    pub god_card_url: String,
+    #[serde(rename(deserialize = "godIcon_URL"), alias = "god_icon_url")]
+    pub god_icon_url: String,
+    #[serde(rename(deserialize = "id"))]
+    pub id: GodId,
+    #[serde(
+        rename(deserialize = "latestGod"),
+        deserialize_with = "crate::utils::bool_from_string",
+        alias = "latest_god"
+    )]
+    pub latest_god: bool,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// A god ability collected from the numbered `Ability*`, `abilityDescription*`
+/// and `godAbility*_URL` fields of `God`.
+#[derive(Debug, Clone, Copy)]
+pub struct GodAbility<'a> {
+    /// Ability slot, from 1 to 5. Slot 5 is the passive.
+    pub slot: u8,
+    pub id: AbilityId,
+    pub name: &'a str,
+    pub details: &'a Ability,
+    pub description: &'a AbilityDescription,
+    pub icon_url: &'a str,
+}
+
+impl God {
+    /// Returns the five abilities of the god in slot order, the passive being last.
+    #[must_use]
+    pub fn abilities(&self) -> [GodAbility<'_>; 5] {
+        [
+            GodAbility {
+                slot: 1,
+                id: self.ability_id1,
+                name: &self.ability1,
+                details: &self.ability_1,
+                description: &self.ability_description1,
+                icon_url: &self.god_ability1_url,
+            },
+            GodAbility {
+                slot: 2,
+                id: self.ability_id2,
+                name: &self.ability2,
+                details: &self.ability_2,
+                description: &self.ability_description2,
+                icon_url: &self.god_ability2_url,
+            },
+            GodAbility {
+                slot: 3,
+                id: self.ability_id3,
+                name: &self.ability3,
+                details: &self.ability_3,
+                description: &self.ability_description3,
+                icon_url: &self.god_ability3_url,
+            },
+            GodAbility {
+                slot: 4,
+                id: self.ability_id4,
+                name: &self.ability4,
+                details: &self.ability_4,
+                description: &self.ability_description4,
+                icon_url: &self.god_ability4_url,
+            },
+            GodAbility {
+                slot: 5,
+                id: self.ability_id5,
+                name: &self.ability5,
+                details: &self.ability_5,
+                description: &self.ability_description5,
+                icon_url: &self.god_ability5_url,
+            },
+        ]
+    }
+
+    /// Returns the ability with the given id, if the god has it.
+    #[must_use]
+    pub fn ability(&self, id: impl Into<AbilityId>) -> Option<GodAbility<'_>> {
+        let id: AbilityId = id.into();
+        self.abilities()
+            .into_iter()
+            .find(|ability| ability.id == id)
+    }
+}
+
+impl Client {
+    /// Retrieves all Gods and their various attributes.
+    ///
+    /// - `language` - The language to retrieve god names in.
+    ///   By default, the English names will be returned.
+    ///
+    /// # Errors
+    ///
+    /// - If the API request fails.
+    pub async fn get_gods(&self, language: Option<Language>) -> Result<Vec<God>> {
+        let language_code = language.unwrap_or_default().code().to_string();
+        self.make_cached_request(CacheCategory::Gods, "getgods", &[&language_code])
+            .await
+    }
+}
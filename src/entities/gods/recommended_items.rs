@@ -0,0 +1,129 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::cache::CacheCategory;
+use crate::client::Client;
+use crate::entities::ids::{GodId, ItemId};
+use crate::entities::language::Language;
+use crate::error::Result;
+
+/// An item of the official recommended build of a god.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct RecommendedItem {
+    #[serde(rename(deserialize = "Category"), alias = "category")]
+    pub category: RecommendedCategory,
+    #[serde(rename(deserialize = "Item"), alias = "item")]
+    pub item: String,
+    #[serde(rename(deserialize = "Role"), alias = "role")]
+    pub role: String,
+    #[serde(rename(deserialize = "category_value_id"))]
+    pub category_value_id: u32,
+    #[serde(rename(deserialize = "god_id"))]
+    pub god_id: GodId,
+    #[serde(rename(deserialize = "god_name"))]
+    pub god_name: String,
+    #[serde(rename(deserialize = "icon_id"))]
+    pub icon_id: u32,
+    #[serde(rename(deserialize = "item_id"))]
+    pub item_id: ItemId,
+    #[serde(rename(deserialize = "role_value_id"))]
+    pub role_value_id: u32,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// Section of the recommended build an item belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RecommendedCategory {
+    Starter,
+    Core,
+    Damage,
+    Defensive,
+    Relic,
+    Consumable,
+    Other(String),
+}
+
+impl FromStr for RecommendedCategory {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let category = match s.trim().to_lowercase().as_str() {
+            "starter" => RecommendedCategory::Starter,
+            "core" => RecommendedCategory::Core,
+            "damage" => RecommendedCategory::Damage,
+            "defensive" => RecommendedCategory::Defensive,
+            "relic" => RecommendedCategory::Relic,
+            "consumable" => RecommendedCategory::Consumable,
+            _ => RecommendedCategory::Other(s.trim().to_string()),
+        };
+
+        Ok(category)
+    }
+}
+
+impl fmt::Display for RecommendedCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RecommendedCategory::Starter => "Starter",
+            RecommendedCategory::Core => "Core",
+            RecommendedCategory::Damage => "Damage",
+            RecommendedCategory::Defensive => "Defensive",
+            RecommendedCategory::Relic => "Relic",
+            RecommendedCategory::Consumable => "Consumable",
+            RecommendedCategory::Other(name) => name,
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for RecommendedCategory {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|never| match never {})
+    }
+}
+
+impl Serialize for RecommendedCategory {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Client {
+    /// Retrieves the recommended items of the god with the given id.
+    ///
+    /// - `language` - The language to retrieve item names in.
+    ///   By default, the English names will be returned.
+    ///
+    /// # Errors
+    ///
+    /// - If the API request fails.
+    pub async fn get_god_recommended_items(
+        &self,
+        god_id: GodId,
+        language: Option<Language>,
+    ) -> Result<Vec<RecommendedItem>> {
+        let language_code = language.unwrap_or_default().code().to_string();
+        self.make_cached_request(
+            CacheCategory::Gods,
+            "getgodrecommendeditems",
+            &[&god_id.to_string(), &language_code],
+        )
+        .await
+    }
+}
@@ -0,0 +1,151 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::cache::CacheCategory;
+use crate::client::Client;
+use crate::entities::game::player_game_info::PlayerGameInfo;
+use crate::entities::ids::{GodId, SkinId};
+use crate::entities::language::Language;
+use crate::error::Result;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct GodSkin {
+    #[serde(rename(deserialize = "godIcon_URL"), alias = "god_icon_url")]
+    pub god_icon_url: String,
+    #[serde(rename(deserialize = "godSkin_URL"), alias = "god_skin_url")]
+    pub god_skin_url: String,
+    #[serde(rename(deserialize = "god_id"))]
+    pub god_id: GodId,
+    #[serde(rename(deserialize = "god_name"))]
+    pub god_name: String,
+    #[serde(rename(deserialize = "obtainability"))]
+    pub obtainability: Obtainability,
+    #[serde(rename(deserialize = "price_favor"))]
+    pub price_favor: u32,
+    #[serde(rename(deserialize = "price_gems"))]
+    pub price_gems: u32,
+    #[serde(default)]
+    pub rarity: Option<String>,
+    #[serde(rename(deserialize = "skin_id1"))]
+    pub skin_id1: SkinId,
+    #[serde(rename(deserialize = "skin_id2"))]
+    pub skin_id2: SkinId,
+    #[serde(rename(deserialize = "skin_name"))]
+    pub skin_name: String,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// How a skin can be obtained.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Obtainability {
+    Normal,
+    Limited,
+    Exclusive,
+    Unlimited,
+    Other(String),
+}
+
+impl GodSkin {
+    /// Returns `true` if the skin has the given id, as reported in `PlayerGameInfo::skin_id`.
+    #[must_use]
+    pub fn has_id(&self, skin_id: SkinId) -> bool {
+        self.skin_id1 == skin_id || self.skin_id2 == skin_id
+    }
+
+    /// Returns `true` if the skin can be bought with favor or gems.
+    #[must_use]
+    pub fn is_purchasable(&self) -> bool {
+        self.price_favor > 0 || self.price_gems > 0
+    }
+}
+
+impl PlayerGameInfo {
+    /// Finds the skin used by the player among the skins of their god.
+    #[must_use]
+    pub fn find_skin<'a>(&self, skins: &'a [GodSkin]) -> Option<&'a GodSkin> {
+        skins
+            .iter()
+            .find(|skin| skin.god_id == self.god_id && skin.has_id(self.skin_id))
+    }
+}
+
+impl FromStr for Obtainability {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let obtainability = match s.trim().to_lowercase().as_str() {
+            "normal" => Obtainability::Normal,
+            "limited" => Obtainability::Limited,
+            "exclusive" => Obtainability::Exclusive,
+            "unlimited" => Obtainability::Unlimited,
+            _ => Obtainability::Other(s.trim().to_string()),
+        };
+
+        Ok(obtainability)
+    }
+}
+
+impl fmt::Display for Obtainability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Obtainability::Normal => "Normal",
+            Obtainability::Limited => "Limited",
+            Obtainability::Exclusive => "Exclusive",
+            Obtainability::Unlimited => "Unlimited",
+            Obtainability::Other(name) => name,
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Obtainability {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|never| match never {})
+    }
+}
+
+impl Serialize for Obtainability {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Client {
+    /// Retrieves all available skins of the god with the given id.
+    ///
+    /// - `language` - The language to retrieve skin names in.
+    ///   By default, the English names will be returned.
+    ///
+    /// # Errors
+    ///
+    /// - If the API request fails.
+    pub async fn get_god_skins(
+        &self,
+        god_id: GodId,
+        language: Option<Language>,
+    ) -> Result<Vec<GodSkin>> {
+        let language_code = language.unwrap_or_default().code().to_string();
+        self.make_cached_request(
+            CacheCategory::Skins,
+            "getgodskins",
+            &[&god_id.to_string(), &language_code],
+        )
+        .await
+    }
+}
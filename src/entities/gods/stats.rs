@@ -0,0 +1,247 @@
+use serde::Serialize;
+
+use crate::entities::items::Item;
+
+use super::attributes::DamageType;
+use super::God;
+
+pub const MAX_LEVEL: u32 = 20;
+const MAX_ATTACK_SPEED: f64 = 2.5;
+
+/// Stats of a god at a given level, optionally including items.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct GodStats {
+    pub level: u32,
+    pub health: f64,
+    pub mana: f64,
+    pub health_per_five: f64,
+    pub mana_per_five: f64,
+    pub physical_protection: f64,
+    pub magical_protection: f64,
+    /// Physical power granted by items. The god's own power is part of
+    /// `basic_attack_damage`.
+    pub item_physical_power: f64,
+    /// Magical power granted by items. The god's own power is part of
+    /// `basic_attack_damage`.
+    pub item_magical_power: f64,
+    pub attack_speed: f64,
+    pub speed: f64,
+    /// Damage of a single basic attack, including power scaling.
+    pub basic_attack_damage: f64,
+}
+
+impl GodStats {
+    /// Effective health against physical damage.
+    #[must_use]
+    pub fn physical_ehp(&self) -> f64 {
+        ehp(self.health, self.physical_protection)
+    }
+
+    /// Effective health against magical damage.
+    #[must_use]
+    pub fn magical_ehp(&self) -> f64 {
+        ehp(self.health, self.magical_protection)
+    }
+
+    /// Damage per second of basic attacks, ignoring the target's protections.
+    #[must_use]
+    pub fn basic_attack_dps(&self) -> f64 {
+        self.basic_attack_damage * self.attack_speed
+    }
+}
+
+impl God {
+    /// Computes the base stats of the god at the given level.
+    /// Levels are clamped to `1..=20`.
+    #[must_use]
+    pub fn stats_at_level(&self, level: u32) -> GodStats {
+        self.stats_with_items(level, &[])
+    }
+
+    /// Computes the stats of the god at the given level with the given items.
+    /// Levels are clamped to `1..=20`.
+    #[must_use]
+    pub fn stats_with_items(&self, level: u32, items: &[&Item]) -> GodStats {
+        let level = level.clamp(1, MAX_LEVEL);
+        let gained = f64::from(level - 1);
+        let base = |value: f32, per_level: f32| f64::from(value) + f64::from(per_level) * gained;
+
+        let item_stat =
+            |name: &str| -> f64 { items.iter().filter_map(|item| item.stat(name)).sum() };
+        let protections = item_stat("Protections");
+
+        let item_physical_power = item_stat("Physical Power");
+        let item_magical_power = item_stat("Magical Power");
+
+        let base_damage = base(self.physical_power, self.physical_power_per_level)
+            + base(self.magical_power, self.magical_power_per_level);
+        let basic_attack_damage = match self.r#type.damage {
+            Some(DamageType::Magical) => base_damage + item_magical_power * 0.2,
+            _ => base_damage + item_physical_power,
+        };
+
+        let attack_speed = f64::from(self.attack_speed)
+            * (1.0
+                + f64::from(self.attack_speed_per_level) * gained
+                + item_stat("Attack Speed") / 100.0);
+        let speed = f64::from(self.speed) * (1.0 + item_stat("Movement Speed") / 100.0);
+
+        GodStats {
+            level,
+            health: base(self.health, self.health_per_level) + item_stat("Health"),
+            mana: base(self.mana, self.mana_per_level) + item_stat("Mana"),
+            health_per_five: base(self.health_per_five, self.hp5_per_level) + item_stat("HP5"),
+            mana_per_five: base(self.mana_per_five, self.mp5_per_level) + item_stat("MP5"),
+            physical_protection: base(self.physical_protection, self.physical_protection_per_level)
+                + item_stat("Physical Protection")
+                + protections,
+            magical_protection: base(self.magic_protection, self.magic_protection_per_level)
+                + item_stat("Magical Protection")
+                + protections,
+            item_physical_power,
+            item_magical_power,
+            attack_speed: attack_speed.min(MAX_ATTACK_SPEED),
+            speed,
+            basic_attack_damage,
+        }
+    }
+}
+
+fn ehp(health: f64, protection: f64) -> f64 {
+    health * (100.0 + protection) / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GodStats, MAX_ATTACK_SPEED};
+    use crate::entities::gods::attributes::DamageType;
+    use crate::entities::gods::God;
+    use crate::entities::items::{Item, ItemStat};
+
+    /// God stats are `f32` in the API, so per-level rates like `0.01` aren't exact.
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    /// A physical god gaining 10 health, 1 protection and 2 power per level.
+    fn god() -> God {
+        let mut god: God =
+            serde_json::from_str(include_str!("../../../tests/fixtures/god.json")).unwrap();
+        god.r#type.damage = Some(DamageType::Physical);
+        god.health = 500.0;
+        god.health_per_level = 10.0;
+        god.mana = 200.0;
+        god.mana_per_level = 5.0;
+        god.health_per_five = 8.0;
+        god.hp5_per_level = 0.5;
+        god.mana_per_five = 4.0;
+        god.mp5_per_level = 0.25;
+        god.physical_protection = 15.0;
+        god.physical_protection_per_level = 1.0;
+        god.magic_protection = 30.0;
+        god.magic_protection_per_level = 1.0;
+        god.physical_power = 40.0;
+        god.physical_power_per_level = 2.0;
+        god.magical_power = 0.0;
+        god.magical_power_per_level = 0.0;
+        god.attack_speed = 1.0;
+        god.attack_speed_per_level = 0.01;
+        god.speed = 360.0;
+
+        god
+    }
+
+    fn item(stats: &[(&str, &str)]) -> Item {
+        let mut item: Item =
+            serde_json::from_str(include_str!("../../../tests/fixtures/item.json")).unwrap();
+        item.item_description.menuitems = stats
+            .iter()
+            .map(|(description, value)| ItemStat {
+                description: (*description).to_string(),
+                value: (*value).to_string(),
+            })
+            .collect();
+
+        item
+    }
+
+    #[test]
+    fn computes_base_stats_at_level_1_and_20() {
+        let god = god();
+
+        let first: GodStats = god.stats_at_level(1);
+        assert_eq!(first.level, 1);
+        assert!(close(first.health, 500.0));
+        assert!(close(first.physical_protection, 15.0));
+        assert!(close(first.basic_attack_damage, 40.0));
+        assert!(close(first.attack_speed, 1.0));
+        assert!(close(first.item_physical_power, 0.0));
+
+        let last = god.stats_at_level(20);
+        assert_eq!(last.level, 20);
+        assert!(close(last.health, 690.0));
+        assert!(close(last.mana, 295.0));
+        assert!(close(last.health_per_five, 17.5));
+        assert!(close(last.mana_per_five, 8.75));
+        assert!(close(last.physical_protection, 34.0));
+        assert!(close(last.magical_protection, 49.0));
+        assert!(close(last.basic_attack_damage, 78.0));
+        assert!(close(last.attack_speed, 1.19));
+    }
+
+    #[test]
+    fn clamps_levels() {
+        let god = god();
+
+        assert_eq!(god.stats_at_level(0), god.stats_at_level(1));
+        assert_eq!(god.stats_at_level(30), god.stats_at_level(20));
+    }
+
+    #[test]
+    fn stacks_stats_of_items() {
+        let god = god();
+        let blade = item(&[("Physical Power", "+30"), ("Attack Speed", "+20%")]);
+        let armor = item(&[("Protections", "+10"), ("Physical Protection", "+20")]);
+        let boots = item(&[("Physical Power", "+10"), ("Movement Speed", "+10%")]);
+
+        let stats = god.stats_with_items(1, &[&blade, &armor, &boots]);
+        assert!(close(stats.item_physical_power, 40.0));
+        assert!(close(stats.item_magical_power, 0.0));
+        assert!(close(stats.basic_attack_damage, 80.0));
+        assert!(close(stats.attack_speed, 1.2));
+        assert!(close(stats.physical_protection, 45.0));
+        assert!(close(stats.magical_protection, 40.0));
+        assert!(close(stats.speed, 396.0));
+    }
+
+    #[test]
+    fn scales_magical_basic_attacks_with_a_fifth_of_item_power() {
+        let mut god = god();
+        god.r#type.damage = Some(DamageType::Magical);
+        let book = item(&[("Magical Power", "+100")]);
+
+        let stats = god.stats_with_items(1, &[&book]);
+        assert!(close(stats.item_magical_power, 100.0));
+        assert!(close(stats.basic_attack_damage, 60.0));
+    }
+
+    #[test]
+    fn caps_attack_speed() {
+        let god = god();
+        let bow = item(&[("Attack Speed", "+500%")]);
+
+        assert!(close(
+            god.stats_with_items(1, &[&bow]).attack_speed,
+            MAX_ATTACK_SPEED
+        ));
+    }
+
+    #[test]
+    fn computes_effective_health_and_dps() {
+        let stats = god().stats_at_level(1);
+
+        assert!(close(stats.physical_ehp(), 575.0));
+        assert!(close(stats.magical_ehp(), 650.0));
+        assert!(close(stats.basic_attack_dps(), 40.0));
+    }
+}
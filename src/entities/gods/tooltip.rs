@@ -0,0 +1,354 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::Serialize;
+
+use super::{AbilityDescription, ItemDescription, Menuitem, Rankitem};
+
+/// A single line of an ability or basic attack tooltip, parsed from the
+/// `menuitems` / `rankitems` string pairs.
+///
+/// For example `("Damage:", "80/130/180/230/280 (+50% of your Magical Power)")`
+/// is parsed into `values = [80, 130, 180, 230, 280]` and a single 50% scaling
+/// from magical power.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AbilityStat {
+    pub label: String,
+    /// Base values per rank. A single value applies to every rank.
+    pub values: Vec<f64>,
+    pub scalings: Vec<Scaling>,
+    /// Unparsed tooltip value, kept for stats like `"Yes"` or `"Cone"`.
+    pub raw: String,
+}
+
+/// A percentage of some stat added on top of the base values.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Scaling {
+    /// Percentages per rank. A single value applies to every rank.
+    pub percents: Vec<f64>,
+    pub source: ScalingSource,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScalingSource {
+    MagicalPower,
+    PhysicalPower,
+    MaximumHealth,
+    Other(String),
+}
+
+/// A tooltip parsed once, for repeated lookups of its stats.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+pub struct Tooltip {
+    pub stats: Vec<AbilityStat>,
+    pub cost: AbilityCost,
+}
+
+/// Per-rank cooldown and cost of an ability or item.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+pub struct AbilityCost {
+    /// Cooldowns in seconds per rank.
+    pub cooldowns: Vec<f64>,
+    /// Costs per rank, usually mana.
+    pub costs: Vec<f64>,
+}
+
+impl Tooltip {
+    /// Returns the stat with the given label, e.g. `"Damage"`.
+    #[must_use]
+    pub fn stat(&self, label: &str) -> Option<&AbilityStat> {
+        let label = label.trim().trim_end_matches(':');
+        self.stats
+            .iter()
+            .find(|stat| stat.label.eq_ignore_ascii_case(label))
+    }
+}
+
+impl AbilityStat {
+    /// Returns the base value at the given rank (starting from 1).
+    #[must_use]
+    pub fn value_at(&self, rank: usize) -> Option<f64> {
+        at_rank(&self.values, rank)
+    }
+
+    /// Returns the value at the given rank including scalings from
+    /// magical or physical power.
+    #[must_use]
+    pub fn value_with_power(&self, rank: usize, power: f64) -> Option<f64> {
+        if self.values.is_empty() && self.scalings.is_empty() {
+            return None;
+        }
+
+        let base = if self.values.is_empty() {
+            0.0
+        } else {
+            self.value_at(rank)?
+        };
+        let scaling: f64 = self
+            .scalings
+            .iter()
+            .filter(|scaling| {
+                matches!(
+                    scaling.source,
+                    ScalingSource::MagicalPower | ScalingSource::PhysicalPower
+                )
+            })
+            .filter_map(|scaling| scaling.percent_at(rank))
+            .map(|percent| percent / 100.0 * power)
+            .sum();
+
+        Some(base + scaling)
+    }
+}
+
+impl Scaling {
+    #[must_use]
+    pub fn percent_at(&self, rank: usize) -> Option<f64> {
+        at_rank(&self.percents, rank)
+    }
+}
+
+impl AbilityCost {
+    #[must_use]
+    pub fn cooldown_at(&self, rank: usize) -> Option<f64> {
+        at_rank(&self.cooldowns, rank)
+    }
+
+    #[must_use]
+    pub fn cost_at(&self, rank: usize) -> Option<f64> {
+        at_rank(&self.costs, rank)
+    }
+}
+
+impl AbilityDescription {
+    /// Parses the `menuitems` and `rankitems` of the tooltip.
+    #[must_use]
+    pub fn stats(&self) -> Vec<AbilityStat> {
+        parse_stats(&self.menuitems, &self.rankitems)
+    }
+
+    /// Parses the `cooldown` and `cost` of the tooltip.
+    #[must_use]
+    pub fn cost(&self) -> AbilityCost {
+        parse_cost(self.cooldown.as_deref(), self.cost.as_deref())
+    }
+
+    /// Parses the whole tooltip. Prefer it to `stats` and `cost` to look up many stats.
+    #[must_use]
+    pub fn tooltip(&self) -> Tooltip {
+        Tooltip {
+            stats: self.stats(),
+            cost: self.cost(),
+        }
+    }
+}
+
+impl ItemDescription {
+    /// Parses the `menuitems` and `rankitems` of the tooltip.
+    #[must_use]
+    pub fn stats(&self) -> Vec<AbilityStat> {
+        parse_stats(&self.menuitems, &self.rankitems)
+    }
+
+    /// Parses the `cooldown` and `cost` of the tooltip.
+    #[must_use]
+    pub fn cost(&self) -> AbilityCost {
+        parse_cost(self.cooldown.as_deref(), self.cost.as_deref())
+    }
+
+    /// Parses the whole tooltip. Prefer it to `stats` and `cost` to look up many stats.
+    #[must_use]
+    pub fn tooltip(&self) -> Tooltip {
+        Tooltip {
+            stats: self.stats(),
+            cost: self.cost(),
+        }
+    }
+}
+
+impl From<&str> for ScalingSource {
+    fn from(source: &str) -> Self {
+        let source = source.trim();
+        match source.to_lowercase().as_str() {
+            "magical power" => ScalingSource::MagicalPower,
+            "physical power" => ScalingSource::PhysicalPower,
+            "maximum health" | "max health" => ScalingSource::MaximumHealth,
+            _ => ScalingSource::Other(source.to_string()),
+        }
+    }
+}
+
+fn at_rank(values: &[f64], rank: usize) -> Option<f64> {
+    match values {
+        [] => None,
+        [value] => Some(*value),
+        values => values.get(rank.checked_sub(1)?).copied(),
+    }
+}
+
+fn parse_stats(menuitems: &[Menuitem], rankitems: &[Rankitem]) -> Vec<AbilityStat> {
+    menuitems
+        .iter()
+        .map(|item| (item.description.as_str(), item.value.as_str()))
+        .chain(
+            rankitems
+                .iter()
+                .map(|item| (item.description.as_str(), item.value.as_str())),
+        )
+        .map(|(label, value)| parse_stat(label, value))
+        .collect()
+}
+
+fn parse_stat(label: &str, value: &str) -> AbilityStat {
+    let scaling = scaling_regex();
+
+    let base = value.split('(').next().unwrap_or_default();
+    let values = if scaling.is_match(base) {
+        Vec::new()
+    } else {
+        parse_numbers(base)
+    };
+
+    let scalings = scaling
+        .captures_iter(value)
+        .map(|cap| Scaling {
+            percents: parse_numbers(&cap[1]),
+            source: ScalingSource::from(&cap[2]),
+        })
+        .collect();
+
+    AbilityStat {
+        label: label.trim().trim_end_matches(':').to_string(),
+        values,
+        scalings,
+        raw: value.to_string(),
+    }
+}
+
+fn parse_cost(cooldown: Option<&str>, cost: Option<&str>) -> AbilityCost {
+    AbilityCost {
+        cooldowns: cooldown.map(parse_numbers).unwrap_or_default(),
+        costs: cost.map(parse_numbers).unwrap_or_default(),
+    }
+}
+
+/// Parses `/`-separated rank values like `"80/130/180"` or `"4/4.5/5s"`.
+/// Returns an empty vector if any of the ranks is not a number.
+fn parse_numbers(text: &str) -> Vec<f64> {
+    let number = number_regex();
+    text.split('/')
+        .map(|part| {
+            number
+                .find(part)
+                .and_then(|found| found.as_str().parse::<f64>().ok())
+        })
+        .collect::<Option<Vec<f64>>>()
+        .unwrap_or_default()
+}
+
+fn number_regex() -> &'static Regex {
+    static NUMBER: OnceLock<Regex> = OnceLock::new();
+    NUMBER.get_or_init(|| Regex::new(r"-?\d+(?:\.\d+)?").expect("Couldn't parse regex"))
+}
+
+/// Matches e.g. `"50% of your Magical Power)"`, capturing the percents and the source.
+fn scaling_regex() -> &'static Regex {
+    static SCALING: OnceLock<Regex> = OnceLock::new();
+    SCALING.get_or_init(|| {
+        Regex::new(
+            r"(?i)([\d./]+)%\s+of\s+(?:your\s+|the\s+target's\s+|target's\s+)?([a-z ]+?)\s*(?:\)|$|,|\.)",
+        )
+        .expect("Couldn't parse regex")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AbilityDescription, Menuitem, Rankitem, ScalingSource};
+
+    fn description(cooldown: &str, cost: &str, rankitems: &[(&str, &str)]) -> AbilityDescription {
+        AbilityDescription {
+            cooldown: Some(cooldown.to_string()),
+            cost: Some(cost.to_string()),
+            description: None,
+            menuitems: vec![Menuitem {
+                description: "Ability Type:".to_string(),
+                value: "Cone".to_string(),
+            }],
+            rankitems: rankitems
+                .iter()
+                .map(|(label, value)| Rankitem {
+                    description: (*label).to_string(),
+                    value: (*value).to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_per_rank_values_and_power_scaling() {
+        let ability = description(
+            "12s",
+            "60",
+            &[("Damage:", "80/130/180/230/280 (+50% of your Magical Power)")],
+        );
+
+        let tooltip = ability.tooltip();
+        let damage = tooltip.stat("Damage").unwrap();
+        assert_eq!(damage.values, [80.0, 130.0, 180.0, 230.0, 280.0]);
+        assert_eq!(damage.value_at(3), Some(180.0));
+        assert_eq!(damage.value_at(6), None);
+        assert_eq!(damage.scalings.len(), 1);
+        assert_eq!(damage.scalings[0].source, ScalingSource::MagicalPower);
+        assert_eq!(damage.scalings[0].percent_at(5), Some(50.0));
+        assert_eq!(damage.value_with_power(2, 100.0), Some(180.0));
+    }
+
+    #[test]
+    fn parses_percent_scalings_per_rank() {
+        let ability = description(
+            "15s",
+            "70",
+            &[
+                ("Healing:", "10/12.5/15/17.5/20% of your Maximum Health"),
+                ("Slow:", "20/25/30/35/40%"),
+            ],
+        );
+
+        let tooltip = ability.tooltip();
+        let healing = tooltip.stat("Healing:").unwrap();
+        assert!(healing.values.is_empty());
+        assert_eq!(healing.scalings[0].source, ScalingSource::MaximumHealth);
+        assert_eq!(healing.scalings[0].percent_at(2), Some(12.5));
+        assert_eq!(healing.value_with_power(2, 100.0), Some(0.0));
+
+        let slow = tooltip.stat("slow").unwrap();
+        assert_eq!(slow.values, [20.0, 25.0, 30.0, 35.0, 40.0]);
+        assert!(slow.scalings.is_empty());
+    }
+
+    #[test]
+    fn keeps_raw_value_of_non_numeric_stats() {
+        let ability = description("10s", "50", &[]);
+
+        let tooltip = ability.tooltip();
+        let kind = tooltip.stat("Ability Type").unwrap();
+        assert!(kind.values.is_empty());
+        assert_eq!(kind.raw, "Cone");
+    }
+
+    #[test]
+    fn separates_mana_costs_from_cooldowns() {
+        let ability = description("16/15/14/13/12s", "60/65/70/75/80 mana", &[]);
+
+        let cost = ability.tooltip().cost;
+        assert_eq!(cost.cooldowns, [16.0, 15.0, 14.0, 13.0, 12.0]);
+        assert_eq!(cost.costs, [60.0, 65.0, 70.0, 75.0, 80.0]);
+        assert_eq!(cost.cooldown_at(5), Some(12.0));
+        assert_eq!(cost.cost_at(1), Some(60.0));
+
+        let flat = description("8s", "", &[]).cost();
+        assert_eq!(flat.cooldown_at(4), Some(8.0));
+        assert!(flat.costs.is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+//! Typed ids shared by all endpoints.
+//!
+//! The API reports ids either as numbers or as strings depending on the endpoint.
+//! Every id type accepts both when deserializing, and is serialized as a number.
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident($inner:ty)) => {
+        $(#[$meta])*
+        #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        #[serde(transparent)]
+        pub struct $name(pub $inner);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.trim().parse().map($name)
+            }
+        }
+
+        impl From<$inner> for $name {
+            fn from(id: $inner) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                crate::utils::deserialize_string_or_number(deserializer)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// Id of a player, as in `Player::id`.
+    PlayerId(u32)
+);
+id_type!(
+    /// Id of a god, as in `God::id`.
+    GodId(u32)
+);
+id_type!(
+    /// Id of an item or relic, as in `Item::item_id`.
+    ItemId(u32)
+);
+id_type!(
+    /// Id of a match, as in `PlayerGameInfo::match_id`.
+    MatchId(u64)
+);
+id_type!(
+    /// Id of a clan, as in `Player::team_id`.
+    TeamId(u32)
+);
+id_type!(
+    /// Id of a god skin, as in `PlayerGameInfo::skin_id`.
+    SkinId(u32)
+);
+id_type!(
+    /// Id of a god ability, as in `God::ability_id1`.
+    AbilityId(u32)
+);
+id_type!(
+    /// Id of a party, as in `PlayerGameInfo::party_id`. Solo players have id `0`.
+    PartyId(u64)
+);
+
+impl PlayerId {
+    /// Hidden players are reported with id `0` in match details.
+    #[must_use]
+    pub fn is_hidden(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl PartyId {
+    /// Players who queued alone are reported with party id `0`.
+    #[must_use]
+    pub fn is_solo(&self) -> bool {
+        self.0 == 0
+    }
+}
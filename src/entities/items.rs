@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::cache::CacheCategory;
+use crate::client::Client;
+use crate::entities::ids::ItemId;
+use crate::entities::language::Language;
+use crate::error::Result;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Item {
+    #[serde(
+        rename(deserialize = "ActiveFlag"),
+        deserialize_with = "crate::utils::bool_from_string",
+        alias = "active_flag"
+    )]
+    pub active_flag: bool,
+    #[serde(rename(deserialize = "ChildItemId"), alias = "child_item_id")]
+    pub child_item_id: ItemId,
+    #[serde(rename(deserialize = "DeviceName"), alias = "device_name")]
+    pub device_name: String,
+    #[serde(rename(deserialize = "IconId"), alias = "icon_id")]
+    pub icon_id: u32,
+    #[serde(rename(deserialize = "ItemDescription"), alias = "item_description")]
+    pub item_description: ItemDetails,
+    #[serde(rename(deserialize = "ItemId"), alias = "item_id")]
+    pub item_id: ItemId,
+    #[serde(rename(deserialize = "ItemTier"), alias = "item_tier")]
+    pub item_tier: u32,
+    #[serde(rename(deserialize = "Price"), alias = "price")]
+    pub price: u32,
+    #[serde(rename(deserialize = "RestrictedRoles"), alias = "restricted_roles")]
+    pub restricted_roles: String,
+    #[serde(rename(deserialize = "RootItemId"), alias = "root_item_id")]
+    pub root_item_id: ItemId,
+    #[serde(rename(deserialize = "ShortDesc"), alias = "short_desc")]
+    pub short_desc: String,
+    #[serde(
+        rename(deserialize = "StartingItem"),
+        deserialize_with = "crate::utils::bool_from_string",
+        alias = "starting_item"
+    )]
+    pub starting_item: bool,
+    #[serde(rename(deserialize = "Type"), alias = "type")]
+    pub r#type: String,
+    #[serde(rename(deserialize = "itemIcon_URL"), alias = "item_icon_url")]
+    pub item_icon_url: String,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ItemDetails {
+    #[serde(rename(deserialize = "Description"), alias = "description")]
+    pub description: Option<String>,
+    #[serde(
+        rename(deserialize = "Menuitems"),
+        default = "Vec::new",
+        alias = "menuitems"
+    )]
+    pub menuitems: Vec<ItemStat>,
+    #[serde(
+        rename(deserialize = "SecondaryDescription"),
+        alias = "secondary_description"
+    )]
+    pub secondary_description: Option<String>,
+}
+
+/// A single stat granted by an item, e.g. `("Physical Power", "+40")`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ItemStat {
+    #[serde(rename(deserialize = "Description"), alias = "description")]
+    pub description: String,
+    #[serde(rename(deserialize = "Value"), alias = "value")]
+    pub value: String,
+}
+
+impl ItemStat {
+    /// Returns the numeric value of the stat, ignoring the `+` sign and `%` suffix.
+    #[must_use]
+    pub fn amount(&self) -> Option<f64> {
+        self.value
+            .trim()
+            .trim_start_matches('+')
+            .trim_end_matches('%')
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    #[must_use]
+    pub fn is_percent(&self) -> bool {
+        self.value.trim().ends_with('%')
+    }
+}
+
+impl Item {
+    /// Returns the amount of the stat with the given name, e.g. `"Physical Power"`.
+    #[must_use]
+    pub fn stat(&self, name: &str) -> Option<f64> {
+        self.item_description
+            .menuitems
+            .iter()
+            .find(|stat| stat.description.trim().eq_ignore_ascii_case(name))
+            .and_then(ItemStat::amount)
+    }
+}
+
+impl Client {
+    /// Retrieves all items and their various attributes.
+    ///
+    /// - `language` - The language to retrieve item names in.
+    ///   By default, the English names will be returned.
+    ///
+    /// # Errors
+    ///
+    /// - If the API request fails.
+    pub async fn get_items(&self, language: Option<Language>) -> Result<Vec<Item>> {
+        let language_code = language.unwrap_or_default().code().to_string();
+        self.make_cached_request(CacheCategory::Items, "getitems", &[&language_code])
+            .await
+    }
+}
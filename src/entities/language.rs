@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use futures_util::future::try_join_all;
+use serde::Serialize;
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+
+use super::ids::{GodId, ItemId};
+
+/// Language of names and descriptions returned by localized endpoints.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    French,
+    Chinese,
+    Spanish,
+    SpanishLatinAmerica,
+    Portuguese,
+    Russian,
+    Polish,
+    Turkish,
+}
+
+/// Names of a god or item in several languages.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+pub struct LocalizedName {
+    pub names: HashMap<Language, String>,
+}
+
+impl Language {
+    pub const ALL: [Language; 10] = [
+        Language::English,
+        Language::German,
+        Language::French,
+        Language::Chinese,
+        Language::Spanish,
+        Language::SpanishLatinAmerica,
+        Language::Portuguese,
+        Language::Russian,
+        Language::Polish,
+        Language::Turkish,
+    ];
+
+    /// Returns the code used by the API for the language.
+    #[must_use]
+    pub fn code(&self) -> u32 {
+        match self {
+            Language::English => 1,
+            Language::German => 2,
+            Language::French => 3,
+            Language::Chinese => 5,
+            Language::Spanish => 7,
+            Language::SpanishLatinAmerica => 9,
+            Language::Portuguese => 10,
+            Language::Russian => 11,
+            Language::Polish => 12,
+            Language::Turkish => 13,
+        }
+    }
+}
+
+impl TryFrom<u32> for Language {
+    type Error = Error;
+
+    fn try_from(code: u32) -> Result<Self> {
+        Language::ALL
+            .into_iter()
+            .find(|language| language.code() == code)
+            .ok_or_else(|| Error::InvalidArgument {
+                given: code.to_string(),
+                expected: "one of language codes 1, 2, 3, 5, 7, 9, 10, 11, 12, 13".to_string(),
+            })
+    }
+}
+
+impl LocalizedName {
+    #[must_use]
+    pub fn get(&self, language: Language) -> Option<&str> {
+        self.names.get(&language).map(String::as_str)
+    }
+
+    /// Returns the name in `language`, falling back to English.
+    #[must_use]
+    pub fn get_or_english(&self, language: Language) -> Option<&str> {
+        self.get(language).or_else(|| self.get(Language::English))
+    }
+}
+
+impl Client {
+    /// Retrieves names of all gods in the given languages, keyed by god id.
+    /// Languages are requested concurrently.
+    ///
+    /// # Errors
+    ///
+    /// - If any of the API requests fails.
+    pub async fn get_localized_god_names(
+        &self,
+        languages: &[Language],
+    ) -> Result<HashMap<GodId, LocalizedName>> {
+        let responses = try_join_all(
+            languages
+                .iter()
+                .map(|language| self.get_gods(Some(*language))),
+        )
+        .await?;
+
+        let mut names: HashMap<GodId, LocalizedName> = HashMap::new();
+        for (language, gods) in languages.iter().zip(responses) {
+            for god in gods {
+                names
+                    .entry(god.id)
+                    .or_default()
+                    .names
+                    .insert(*language, god.name);
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Retrieves names of all items in the given languages, keyed by item id.
+    /// Languages are requested concurrently.
+    ///
+    /// # Errors
+    ///
+    /// - If any of the API requests fails.
+    pub async fn get_localized_item_names(
+        &self,
+        languages: &[Language],
+    ) -> Result<HashMap<ItemId, LocalizedName>> {
+        let responses = try_join_all(
+            languages
+                .iter()
+                .map(|language| self.get_items(Some(*language))),
+        )
+        .await?;
+
+        let mut names: HashMap<ItemId, LocalizedName> = HashMap::new();
+        for (language, items) in languages.iter().zip(responses) {
+            for item in items {
+                names
+                    .entry(item.item_id)
+                    .or_default()
+                    .names
+                    .insert(*language, item.device_name);
+            }
+        }
+
+        Ok(names)
+    }
+}
@@ -1,6 +1,12 @@
+pub mod esports;
 pub mod game;
 pub mod gods;
+pub mod ids;
+pub mod items;
+pub mod language;
 pub mod motd;
 pub mod player;
 pub mod session;
 pub mod session_info;
+pub mod status;
+pub mod team;
@@ -1,31 +1,64 @@
-use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use crate::client::Client;
+use crate::entities::game::Queue;
+use crate::entities::ids::GodId;
 use crate::error::Result;
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
+/// Every MOTD is played for a full day.
+const MOTD_DURATION: i64 = 24 * 60 * 60;
+
+/// MOTDs sorted by start time, with helpers to find the current and upcoming ones.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MotdSchedule {
+    pub motds: Vec<MatchOfTheDay>,
+}
+
+/// A single rule of a MOTD, e.g. `Starting Level: 20`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MotdModifier {
+    pub name: String,
+    /// Value of the rule, if it is written as `name: value`.
+    pub value: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all(deserialize = "camelCase"))]
 pub struct MatchOfTheDay {
     pub description: String,
-    #[serde(deserialize_with = "crate::utils::deserialize_non_empty_string")]
+    /// Game mode the MOTD is based on, e.g. `"Conquest"`.
+    #[serde(default, alias = "game_mode")]
+    pub game_mode: Option<String>,
+    #[serde(
+        deserialize_with = "crate::utils::deserialize_non_empty_string",
+        alias = "max_players"
+    )]
     pub max_players: Option<u32>,
     pub name: String,
-    #[serde(rename = "ret_msg")]
+    #[serde(rename(deserialize = "ret_msg"))]
     pub ret_msg: Option<String>,
-    #[serde(deserialize_with = "crate::utils::timestamp_from_string")]
+    #[serde(
+        deserialize_with = "crate::utils::timestamp_from_string",
+        alias = "start_date_time"
+    )]
     pub start_date_time: DateTime<Utc>,
     #[serde(
         deserialize_with = "crate::utils::deserialize_non_empty_string",
-        rename = "team1GodsCSV"
+        rename(deserialize = "team1GodsCSV"),
+        alias = "team1_gods_csv"
     )]
     pub team1_gods_csv: Option<String>,
     #[serde(
         deserialize_with = "crate::utils::deserialize_non_empty_string",
-        rename = "team2GodsCSV"
+        rename(deserialize = "team2GodsCSV"),
+        alias = "team2_gods_csv"
     )]
     pub team2_gods_csv: Option<String>,
     pub title: String,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
 }
 
 impl Client {
@@ -37,9 +70,84 @@ impl Client {
     pub async fn get_motds(&self) -> Result<Vec<MatchOfTheDay>> {
         self.make_request("getmotd", true, &[]).await
     }
+
+    /// Same as `get_motds`, but the MOTDs are sorted into a `MotdSchedule`.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_motd_schedule(&self) -> Result<MotdSchedule> {
+        self.get_motds().await.map(MotdSchedule::from)
+    }
+}
+
+impl From<Vec<MatchOfTheDay>> for MotdSchedule {
+    fn from(mut motds: Vec<MatchOfTheDay>) -> Self {
+        motds.sort_by_key(|motd| motd.start_date_time);
+        MotdSchedule { motds }
+    }
+}
+
+impl MotdSchedule {
+    /// Returns the MOTD being played right now.
+    #[must_use]
+    pub fn current(&self) -> Option<&MatchOfTheDay> {
+        self.active_at(Utc::now())
+    }
+
+    /// Returns the first MOTD that has not started yet.
+    #[must_use]
+    pub fn next(&self) -> Option<&MatchOfTheDay> {
+        self.next_after(Utc::now())
+    }
+
+    #[must_use]
+    pub fn active_at(&self, time: DateTime<Utc>) -> Option<&MatchOfTheDay> {
+        self.motds.iter().rev().find(|motd| motd.is_active_at(time))
+    }
+
+    #[must_use]
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<&MatchOfTheDay> {
+        self.motds.iter().find(|motd| motd.start_date_time > time)
+    }
+
+    /// Returns MOTDs played at any moment between `start` and `end`.
+    #[must_use]
+    pub fn between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<&MatchOfTheDay> {
+        self.motds
+            .iter()
+            .filter(|motd| motd.start_date_time < end && motd.end_date_time() > start)
+            .collect()
+    }
+
+    /// Returns MOTDs that have not started yet.
+    #[must_use]
+    pub fn upcoming(&self) -> Vec<&MatchOfTheDay> {
+        let now = Utc::now();
+        self.motds
+            .iter()
+            .filter(|motd| motd.start_date_time > now)
+            .collect()
+    }
 }
 
 impl MatchOfTheDay {
+    #[must_use]
+    pub fn end_date_time(&self) -> DateTime<Utc> {
+        self.start_date_time + Duration::seconds(MOTD_DURATION)
+    }
+
+    #[must_use]
+    pub fn is_active_at(&self, time: DateTime<Utc>) -> bool {
+        self.start_date_time <= time && time < self.end_date_time()
+    }
+
+    /// Returns the time left until the MOTD starts, or `None` if it has already started.
+    #[must_use]
+    pub fn starts_in(&self) -> Option<Duration> {
+        let left = self.start_date_time.signed_duration_since(Utc::now());
+        (left > Duration::zero()).then_some(left)
+    }
+
     /// API returns the description as a single string with '<li>' tags.
     /// This method return a vector of strings by separating the description by '<li>' tags.
     #[must_use]
@@ -59,4 +167,181 @@ impl MatchOfTheDay {
 
         props
     }
+
+    /// Returns the rules of the MOTD parsed from the description.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn modifiers(&self) -> Vec<MotdModifier> {
+        let tags = regex::Regex::new(r"<[^>]*>").expect("Couldn't parse regex");
+
+        self.separate_description()
+            .into_iter()
+            .map(|modifier| tags.replace_all(modifier, "").trim().to_string())
+            .filter(|modifier| !modifier.is_empty())
+            .map(|modifier| match modifier.split_once(':') {
+                Some((name, value)) if !value.trim().is_empty() => MotdModifier {
+                    name: name.trim().to_string(),
+                    value: Some(value.trim().to_string()),
+                },
+                _ => MotdModifier {
+                    name: modifier.trim_end_matches(':').to_string(),
+                    value: None,
+                },
+            })
+            .collect()
+    }
+
+    /// Returns the gods forced on the first team, if any.
+    #[must_use]
+    pub fn team1_gods(&self) -> Vec<GodId> {
+        parse_god_ids(self.team1_gods_csv.as_deref())
+    }
+
+    /// Returns the gods forced on the second team, if any.
+    #[must_use]
+    pub fn team2_gods(&self) -> Vec<GodId> {
+        parse_god_ids(self.team2_gods_csv.as_deref())
+    }
+
+    #[must_use]
+    pub fn has_forced_gods(&self) -> bool {
+        !self.team1_gods().is_empty() || !self.team2_gods().is_empty()
+    }
+
+    /// Resolves the game mode the MOTD is based on.
+    /// Note that the MOTD matches themselves are listed under `Queue::Motd`.
+    #[must_use]
+    pub fn game_mode_queue(&self) -> Option<Queue> {
+        let game_mode = self.game_mode.as_deref()?.trim().to_lowercase();
+
+        match game_mode.as_str() {
+            "conquest" => Some(Queue::Conquest),
+            "arena" => Some(Queue::Arena),
+            "joust" => Some(Queue::Joust),
+            "assault" => Some(Queue::Assault),
+            _ => None,
+        }
+    }
+}
+
+fn parse_god_ids(csv: Option<&str>) -> Vec<GodId> {
+    csv.unwrap_or_default()
+        .split(',')
+        .filter_map(|id| id.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use serde_json::Map;
+
+    use super::{MatchOfTheDay, MotdSchedule};
+    use crate::entities::ids::GodId;
+
+    fn motd(title: &str, start_date_time: DateTime<Utc>) -> MatchOfTheDay {
+        MatchOfTheDay {
+            description: "<li>Starting Level: 20</li><li>Infinite Mana</li>".to_string(),
+            game_mode: Some("Conquest".to_string()),
+            max_players: Some(10),
+            name: title.to_string(),
+            ret_msg: None,
+            start_date_time,
+            team1_gods_csv: Some("1773,2000".to_string()),
+            team2_gods_csv: None,
+            title: title.to_string(),
+            additional_fields: Map::new(),
+        }
+    }
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, day, 9, 0, 0).unwrap()
+    }
+
+    fn schedule() -> MotdSchedule {
+        MotdSchedule::from(vec![
+            motd("third", day(3)),
+            motd("first", day(1)),
+            motd("second", day(2)),
+        ])
+    }
+
+    fn titles(motds: &[&MatchOfTheDay]) -> Vec<String> {
+        motds.iter().map(|motd| motd.title.clone()).collect()
+    }
+
+    #[test]
+    fn motd_is_active_from_start_until_a_day_later() {
+        let schedule = schedule();
+
+        assert_eq!(schedule.motds[0].title, "first");
+        assert_eq!(schedule.active_at(day(1)).unwrap().title, "first");
+        assert_eq!(
+            schedule
+                .active_at(day(2) - Duration::seconds(1))
+                .unwrap()
+                .title,
+            "first"
+        );
+        assert_eq!(schedule.active_at(day(2)).unwrap().title, "second");
+        assert_eq!(schedule.active_at(day(3) + Duration::hours(24)), None);
+        assert_eq!(schedule.active_at(day(1) - Duration::seconds(1)), None);
+    }
+
+    #[test]
+    fn next_motd_starts_strictly_after() {
+        let schedule = schedule();
+
+        assert_eq!(schedule.next_after(day(1)).unwrap().title, "second");
+        assert_eq!(
+            schedule
+                .next_after(day(1) - Duration::seconds(1))
+                .unwrap()
+                .title,
+            "first"
+        );
+        assert_eq!(schedule.next_after(day(3)), None);
+    }
+
+    #[test]
+    fn between_excludes_motds_touching_the_range() {
+        let schedule = schedule();
+
+        assert_eq!(titles(&schedule.between(day(2), day(3))), ["second"]);
+        assert_eq!(
+            titles(&schedule.between(day(2) - Duration::seconds(1), day(3) + Duration::seconds(1))),
+            ["first", "second", "third"]
+        );
+        assert!(schedule.between(day(4), day(5)).is_empty());
+    }
+
+    #[test]
+    fn current_and_next_use_the_current_time() {
+        let now = Utc::now();
+        let schedule = MotdSchedule::from(vec![
+            motd("today", now - Duration::hours(1)),
+            motd("tomorrow", now + Duration::hours(23)),
+        ]);
+
+        assert_eq!(schedule.current().unwrap().title, "today");
+        assert_eq!(schedule.next().unwrap().title, "tomorrow");
+        assert_eq!(titles(&schedule.upcoming()), ["tomorrow"]);
+        assert!(schedule.motds[1].starts_in().is_some());
+        assert!(schedule.motds[0].starts_in().is_none());
+    }
+
+    #[test]
+    fn parses_modifiers_and_forced_gods() {
+        let motd = motd("first", day(1));
+
+        let modifiers = motd.modifiers();
+        assert_eq!(modifiers[0].name, "Starting Level");
+        assert_eq!(modifiers[0].value.as_deref(), Some("20"));
+        assert_eq!(modifiers[1].name, "Infinite Mana");
+        assert_eq!(modifiers[1].value, None);
+
+        assert_eq!(motd.team1_gods(), [GodId(1773), GodId(2000)]);
+        assert!(motd.team2_gods().is_empty());
+        assert!(motd.has_forced_gods());
+    }
 }
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::ids::PlayerId;
+use crate::error::Result;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct PlayerAchievements {
+    #[serde(rename(deserialize = "AssistedKills"), alias = "assisted_kills")]
+    pub assisted_kills: u32,
+    #[serde(rename(deserialize = "CampsCleared"), alias = "camps_cleared")]
+    pub camps_cleared: u32,
+    #[serde(rename(deserialize = "Deaths"), alias = "deaths")]
+    pub deaths: u32,
+    #[serde(rename(deserialize = "DivineSpree"), alias = "divine_spree")]
+    pub divine_spree: u32,
+    #[serde(rename(deserialize = "DoubleKills"), alias = "double_kills")]
+    pub double_kills: u32,
+    #[serde(rename(deserialize = "FireGiantKills"), alias = "fire_giant_kills")]
+    pub fire_giant_kills: u32,
+    #[serde(rename(deserialize = "FirstBloods"), alias = "first_bloods")]
+    pub first_bloods: u32,
+    #[serde(rename(deserialize = "GodLikeSpree"), alias = "god_like_spree")]
+    pub god_like_spree: u32,
+    #[serde(rename(deserialize = "GoldFuryKills"), alias = "gold_fury_kills")]
+    pub gold_fury_kills: u32,
+    #[serde(rename(deserialize = "Id"), alias = "id")]
+    pub id: PlayerId,
+    #[serde(rename(deserialize = "ImmortalSpree"), alias = "immortal_spree")]
+    pub immortal_spree: u32,
+    #[serde(rename(deserialize = "KillingSpree"), alias = "killing_spree")]
+    pub killing_spree: u32,
+    #[serde(rename(deserialize = "MinionKills"), alias = "minion_kills")]
+    pub minion_kills: u32,
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    pub name: String,
+    #[serde(rename(deserialize = "PentaKills"), alias = "penta_kills")]
+    pub penta_kills: u32,
+    #[serde(rename(deserialize = "PhoenixKills"), alias = "phoenix_kills")]
+    pub phoenix_kills: u32,
+    #[serde(rename(deserialize = "PlayerKills"), alias = "player_kills")]
+    pub player_kills: u32,
+    #[serde(rename(deserialize = "QuadraKills"), alias = "quadra_kills")]
+    pub quadra_kills: u32,
+    #[serde(rename(deserialize = "RampageSpree"), alias = "rampage_spree")]
+    pub rampage_spree: u32,
+    #[serde(rename(deserialize = "ShutdownSpree"), alias = "shutdown_spree")]
+    pub shutdown_spree: u32,
+    #[serde(
+        rename(deserialize = "SiegeJuggernautKills"),
+        alias = "siege_juggernaut_kills"
+    )]
+    pub siege_juggernaut_kills: u32,
+    #[serde(rename(deserialize = "TowerKills"), alias = "tower_kills")]
+    pub tower_kills: u32,
+    #[serde(rename(deserialize = "TripleKills"), alias = "triple_kills")]
+    pub triple_kills: u32,
+    #[serde(rename(deserialize = "UnstoppableSpree"), alias = "unstoppable_spree")]
+    pub unstoppable_spree: u32,
+    #[serde(
+        rename(deserialize = "WildJuggernautKills"),
+        alias = "wild_juggernaut_kills"
+    )]
+    pub wild_juggernaut_kills: u32,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl PlayerAchievements {
+    /// Returns the number of double, triple, quadra and penta kills.
+    #[must_use]
+    pub fn multi_kills(&self) -> u32 {
+        self.double_kills + self.triple_kills + self.quadra_kills + self.penta_kills
+    }
+
+    /// Returns the number of jungle objectives killed: camps, gold furies, fire giants
+    /// and wild juggernauts.
+    #[must_use]
+    pub fn jungle_kills(&self) -> u32 {
+        self.camps_cleared
+            + self.gold_fury_kills
+            + self.fire_giant_kills
+            + self.wild_juggernaut_kills
+    }
+}
+
+impl Client {
+    /// Returns the achievements of a player.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_player_achievements(&self, player_id: PlayerId) -> Result<PlayerAchievements> {
+        self.make_request("getplayerachievements", true, &[&player_id.to_string()])
+            .await
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use futures_util::future::join_all;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::client::Client;
+use crate::entities::ids::PlayerId;
+use crate::error::{Error, Result};
+
+use super::Player;
+
+/// Maximum number of ids accepted by a single `getplayerbatch` request.
+pub const PLAYER_BATCH_LIMIT: usize = 20;
+
+impl Client {
+    /// Returns information about many players at once.
+    /// Ids are split into chunks of `PLAYER_BATCH_LIMIT` which are requested concurrently.
+    /// This method requires a valid session.
+    ///
+    /// Every requested id is present in the returned map. Players that were not found
+    /// map to `Error::PlayerNotFound`, and players with a private profile map to
+    /// `Error::PrivateProfile`. If the request of a chunk fails, every id of that chunk
+    /// maps to `Error::Batch` with the error of the request, and the other chunks are
+    /// kept.
+    pub async fn get_players_batch(
+        &self,
+        player_ids: &[PlayerId],
+    ) -> HashMap<PlayerId, Result<Player>> {
+        let requests = player_ids.chunks(PLAYER_BATCH_LIMIT).map(|chunk| {
+            let ids = chunk
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+
+            async move {
+                let response = self
+                    .make_request::<Vec<Value>>("getplayerbatch", true, &[&ids])
+                    .await;
+
+                (chunk, response)
+            }
+        });
+
+        let mut players: HashMap<PlayerId, Result<Player>> = HashMap::new();
+        for (chunk, response) in join_all(requests).await {
+            match response {
+                Ok(values) => players.extend(
+                    values
+                        .into_iter()
+                        .filter_map(|value| self.parse_batch_entry(value)),
+                ),
+                Err(err) => {
+                    let err = Arc::new(err);
+                    players.extend(chunk.iter().map(|id| (*id, Err(Error::Batch(err.clone())))));
+                }
+            }
+        }
+
+        for id in player_ids {
+            players
+                .entry(*id)
+                .or_insert_with(|| Err(Error::PlayerNotFound(*id)));
+        }
+        players.retain(|id, _| player_ids.contains(id));
+
+        players
+    }
+
+    fn parse_batch_entry(&self, value: Value) -> Option<(PlayerId, Result<Player>)> {
+        let id = value
+            .get("Id")
+            .and_then(|id| PlayerId::deserialize(id).ok())
+            .filter(|id| !id.is_hidden());
+        let ret_msg = value.get("ret_msg").and_then(Value::as_str).unwrap_or("");
+
+        if ret_msg.contains("Privacy") {
+            let id = id.or_else(|| private_player_id(ret_msg))?;
+            return Some((id, Err(Error::PrivateProfile(id))));
+        }
+
+        let id = id?;
+        Some((id, self.parse_value("getplayerbatch", value)))
+    }
+}
+
+/// Private profiles are reported as e.g. `"Player Privacy Flag set for: playerIdStr=123; ..."`.
+fn private_player_id(ret_msg: &str) -> Option<PlayerId> {
+    static PLAYER_ID: OnceLock<Regex> = OnceLock::new();
+    let re =
+        PLAYER_ID.get_or_init(|| Regex::new(r"playerIdStr=(\d+)").expect("Couldn't parse regex"));
+
+    re.captures(ret_msg)?.get(1)?.as_str().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::private_player_id;
+    use crate::client::Client;
+    use crate::entities::ids::PlayerId;
+    use crate::error::Error;
+
+    #[test]
+    fn reads_private_player_id_from_ret_msg() {
+        let ret_msg = "Player Privacy Flag set for: playerIdStr=123; playerNameStr=Foo";
+
+        assert_eq!(private_player_id(ret_msg), Some(PlayerId(123)));
+        assert_eq!(private_player_id("Player Privacy Flag set"), None);
+    }
+
+    #[test]
+    fn maps_private_and_hidden_entries() {
+        let client = Client::new(String::new(), String::new());
+
+        let private = json!({
+            "Id": 0,
+            "ret_msg": "Player Privacy Flag set for: playerIdStr=123; playerNameStr=Foo"
+        });
+        let (id, player) = client.parse_batch_entry(private).unwrap();
+        assert_eq!(id, PlayerId(123));
+        assert!(matches!(player, Err(Error::PrivateProfile(PlayerId(123)))));
+
+        assert!(client
+            .parse_batch_entry(json!({ "Id": 0, "ret_msg": null }))
+            .is_none());
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::ids::PlayerId;
+use crate::error::Result;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Friend {
+    pub account_id: String,
+    pub avatar_url: Option<String>,
+    pub friend_flags: String,
+    pub name: String,
+    /// Id of the friend, usable with other player endpoints.
+    /// Might be missing for players with a private profile.
+    #[serde(deserialize_with = "crate::utils::deserialize_non_empty_string")]
+    pub player_id: Option<PlayerId>,
+    pub portal_id: String,
+    pub status: String,
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl Friend {
+    #[must_use]
+    pub fn is_friend(&self) -> bool {
+        self.status.eq_ignore_ascii_case("friend")
+    }
+}
+
+impl Client {
+    /// Returns the friends of a player.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_friends(&self, player_id: PlayerId) -> Result<Vec<Friend>> {
+        self.make_request("getfriends", true, &[&player_id.to_string()])
+            .await
+    }
+}
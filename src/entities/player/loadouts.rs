@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::ids::{GodId, ItemId, PlayerId};
+use crate::entities::language::Language;
+use crate::error::Result;
+
+/// An item build saved by a player for a god.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct GodLoadout {
+    #[serde(rename(deserialize = "DeckId"), alias = "deck_id")]
+    pub deck_id: u32,
+    #[serde(rename(deserialize = "DeckName"), alias = "deck_name")]
+    pub deck_name: String,
+    #[serde(rename(deserialize = "GodId"), alias = "god_id")]
+    pub god_id: GodId,
+    #[serde(rename(deserialize = "GodName"), alias = "god_name")]
+    pub god_name: String,
+    #[serde(
+        rename(deserialize = "LoadoutItems"),
+        default = "Vec::new",
+        alias = "loadout_items"
+    )]
+    pub loadout_items: Vec<LoadoutItem>,
+    #[serde(rename(deserialize = "playerId"), alias = "player_id")]
+    pub player_id: PlayerId,
+    #[serde(rename(deserialize = "playerName"), alias = "player_name")]
+    pub player_name: String,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LoadoutItem {
+    #[serde(rename(deserialize = "ItemId"), alias = "item_id")]
+    pub item_id: ItemId,
+    #[serde(rename(deserialize = "ItemName"), alias = "item_name")]
+    pub item_name: String,
+}
+
+impl Client {
+    /// Returns the item loadouts saved by a player for a god.
+    ///
+    /// - `language` - The language to retrieve item names in.
+    ///   By default, the English names will be returned.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_god_loadouts(
+        &self,
+        player_id: PlayerId,
+        god_id: GodId,
+        language: Option<Language>,
+    ) -> Result<Vec<GodLoadout>> {
+        let language_code = language.unwrap_or_default().code().to_string();
+        self.make_request(
+            "getgodloadouts",
+            true,
+            &[&player_id.to_string(), &god_id.to_string(), &language_code],
+        )
+        .await
+    }
+}
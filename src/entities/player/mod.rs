@@ -0,0 +1,206 @@
+pub mod achievements;
+pub mod batch;
+pub mod friends;
+pub mod loadouts;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::ids::{PlayerId, TeamId};
+use crate::error::Result;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Player {
+    #[serde(rename(deserialize = "ActivePlayerId"), alias = "active_player_id")]
+    pub active_player_id: PlayerId,
+    #[serde(rename(deserialize = "Avatar_URL"), alias = "avatar_url")]
+    pub avatar_url: String,
+    #[serde(rename(deserialize = "Created_Datetime"), alias = "created_datetime")]
+    pub created_datetime: String,
+    #[serde(rename(deserialize = "HoursPlayed"), alias = "hours_played")]
+    pub hours_played: f64, // Numbers might be represented as f64
+    #[serde(rename(deserialize = "Id"), alias = "id")]
+    pub id: PlayerId,
+    #[serde(
+        rename(deserialize = "Last_Login_Datetime"),
+        alias = "last_login_datetime"
+    )]
+    pub last_login_datetime: String,
+    #[serde(rename(deserialize = "Leaves"), alias = "leaves")]
+    pub leaves: u32,
+    #[serde(rename(deserialize = "Level"), alias = "level")]
+    pub level: u32,
+    #[serde(rename(deserialize = "Losses"), alias = "losses")]
+    pub losses: u32,
+    #[serde(rename(deserialize = "MasteryLevel"), alias = "mastery_level")]
+    pub mastery_level: u32,
+    #[serde(rename(deserialize = "MergedPlayers"), alias = "merged_players")]
+    pub merged_players: Option<Vec<Merged>>,
+    #[serde(rename(deserialize = "MinutesPlayed"), alias = "minutes_played")]
+    pub minutes_played: u32,
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    pub name: String,
+    #[serde(
+        rename(deserialize = "Personal_Status_Message"),
+        alias = "personal_status_message"
+    )]
+    pub personal_status_message: String,
+    #[serde(rename(deserialize = "Platform"), alias = "platform")]
+    pub platform: String,
+    #[serde(
+        rename(deserialize = "Rank_Stat_Conquest"),
+        alias = "rank_stat_conquest"
+    )]
+    pub rank_stat_conquest: Option<u32>, // Might be null
+    #[serde(
+        rename(deserialize = "Rank_Stat_Conquest_Controller"),
+        alias = "rank_stat_conquest_controller"
+    )]
+    pub rank_stat_conquest_controller: Option<u32>,
+    #[serde(rename(deserialize = "Rank_Stat_Duel"), alias = "rank_stat_duel")]
+    pub rank_stat_duel: Option<u32>,
+    #[serde(
+        rename(deserialize = "Rank_Stat_Duel_Controller"),
+        alias = "rank_stat_duel_controller"
+    )]
+    pub rank_stat_duel_controller: Option<u32>,
+    #[serde(rename(deserialize = "Rank_Stat_Joust"), alias = "rank_stat_joust")]
+    pub rank_stat_joust: Option<u32>,
+    #[serde(
+        rename(deserialize = "Rank_Stat_Joust_Controller"),
+        alias = "rank_stat_joust_controller"
+    )]
+    pub rank_stat_joust_controller: Option<u32>,
+    #[serde(rename(deserialize = "RankedConquest"), alias = "ranked_conquest")]
+    pub ranked_conquest: RankedStats,
+    #[serde(
+        rename(deserialize = "RankedConquestController"),
+        alias = "ranked_conquest_controller"
+    )]
+    pub ranked_conquest_controller: RankedStats,
+    #[serde(rename(deserialize = "RankedDuel"), alias = "ranked_duel")]
+    pub ranked_duel: RankedStats,
+    #[serde(
+        rename(deserialize = "RankedDuelController"),
+        alias = "ranked_duel_controller"
+    )]
+    pub ranked_duel_controller: RankedStats,
+    #[serde(rename(deserialize = "RankedJoust"), alias = "ranked_joust")]
+    pub ranked_joust: RankedStats,
+    #[serde(
+        rename(deserialize = "RankedJoustController"),
+        alias = "ranked_joust_controller"
+    )]
+    pub ranked_joust_controller: RankedStats,
+    #[serde(rename(deserialize = "Region"), alias = "region")]
+    pub region: String,
+    #[serde(rename(deserialize = "TeamId"), alias = "team_id")]
+    pub team_id: TeamId,
+    #[serde(rename(deserialize = "Team_Name"), alias = "team_name")]
+    pub team_name: String,
+    #[serde(rename(deserialize = "Tier_Conquest"), alias = "tier_conquest")]
+    pub tier_conquest: u32,
+    #[serde(rename(deserialize = "Tier_Duel"), alias = "tier_duel")]
+    pub tier_duel: u32,
+    #[serde(rename(deserialize = "Tier_Joust"), alias = "tier_joust")]
+    pub tier_joust: u32,
+    #[serde(
+        rename(deserialize = "Total_Achievements"),
+        alias = "total_achievements"
+    )]
+    pub total_achievements: u32,
+    #[serde(rename(deserialize = "Total_Worshippers"), alias = "total_worshippers")]
+    pub total_worshippers: u32,
+    #[serde(rename(deserialize = "Wins"), alias = "wins")]
+    pub wins: u32,
+    #[serde(rename(deserialize = "hz_gamer_tag"))]
+    pub hz_gamer_tag: Option<String>,
+    #[serde(rename(deserialize = "hz_player_name"))]
+    pub hz_player_name: String,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct RankedStats {
+    #[serde(rename(deserialize = "Leaves"), alias = "leaves")]
+    pub leaves: u32,
+    #[serde(rename(deserialize = "Losses"), alias = "losses")]
+    pub losses: u32,
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    pub name: String,
+    #[serde(rename(deserialize = "Points"), alias = "points")]
+    pub points: u32,
+    #[serde(rename(deserialize = "PrevRank"), alias = "prev_rank")]
+    pub prev_rank: u32,
+    #[serde(rename(deserialize = "Rank"), alias = "rank")]
+    pub rank: u32,
+    #[serde(rename(deserialize = "Rank_Stat"), alias = "rank_stat")]
+    pub rank_stat: u32,
+    #[serde(
+        rename(deserialize = "Rank_Stat_Conquest"),
+        alias = "rank_stat_conquest"
+    )]
+    pub rank_stat_conquest: Option<u32>, // Might be null
+    #[serde(rename(deserialize = "Rank_Stat_Joust"), alias = "rank_stat_joust")]
+    pub rank_stat_joust: Option<u32>,
+    #[serde(rename(deserialize = "Rank_Variance"), alias = "rank_variance")]
+    pub rank_variance: u32,
+    #[serde(rename(deserialize = "Round"), alias = "round")]
+    pub round: u32,
+    #[serde(rename(deserialize = "Season"), alias = "season")]
+    pub season: u32,
+    #[serde(rename(deserialize = "Tier"), alias = "tier")]
+    pub tier: u32,
+    #[serde(rename(deserialize = "Trend"), alias = "trend")]
+    pub trend: u32,
+    #[serde(rename(deserialize = "Wins"), alias = "wins")]
+    pub wins: u32,
+    #[serde(
+        rename(deserialize = "player_id"),
+        deserialize_with = "crate::utils::deserialize_non_empty_string"
+    )]
+    pub player_id: Option<PlayerId>,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Merged {
+    #[serde(alias = "player_id")]
+    pub player_id: PlayerId,
+    #[serde(alias = "portal_id")]
+    pub portal_id: String,
+    #[serde(rename(deserialize = "merge_datetime"))]
+    pub merge_datetime: String,
+}
+
+impl Client {
+    /// Returns information about a player.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    /// This function will return an error if .
+    ///
+    /// # Examples
+    /// ```
+    /// use smite::client::Client;
+    /// use smite::error::Result;
+    ///
+    /// async fn example() -> Result<()> {
+    ///    let client = Client::new("dev_id".to_string(), "auth_key".to_string());
+    ///    let player = &client.get_player("my_player").await?[0]; // API may return multiple players.
+    ///
+    ///    Ok(())
+    /// }
+    pub async fn get_player(&self, player_name: &str) -> Result<Vec<Player>> {
+        self.make_request("getplayer", true, &[player_name]).await
+    }
+}
@@ -1,24 +1,42 @@
 use crate::client::Client;
 use crate::error::Result;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct SessionInfo {
-    #[serde(rename = "Active_Sessions")]
+    #[serde(rename(deserialize = "Active_Sessions"), alias = "active_sessions")]
     pub active_sessions: i64,
-    #[serde(rename = "Concurrent_Sessions")]
+    #[serde(
+        rename(deserialize = "Concurrent_Sessions"),
+        alias = "concurrent_sessions"
+    )]
     pub concurrent_sessions: i64,
-    #[serde(rename = "Request_Limit_Daily")]
+    #[serde(
+        rename(deserialize = "Request_Limit_Daily"),
+        alias = "request_limit_daily"
+    )]
     pub request_limit_daily: i64,
-    #[serde(rename = "Session_Cap")]
+    #[serde(rename(deserialize = "Session_Cap"), alias = "session_cap")]
     pub session_cap: i64,
-    #[serde(rename = "Session_Time_Limit")]
+    #[serde(
+        rename(deserialize = "Session_Time_Limit"),
+        alias = "session_time_limit"
+    )]
     pub session_time_limit: i64,
-    #[serde(rename = "Total_Requests_Today")]
+    #[serde(
+        rename(deserialize = "Total_Requests_Today"),
+        alias = "total_requests_today"
+    )]
     pub total_requests_today: i64,
-    #[serde(rename = "Total_Sessions_Today")]
+    #[serde(
+        rename(deserialize = "Total_Sessions_Today"),
+        alias = "total_sessions_today"
+    )]
     pub total_sessions_today: i64,
     pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
 }
 
 impl SessionInfo {
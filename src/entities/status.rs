@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct PatchInfo {
+    pub version_string: String,
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// Status of the Hi-Rez servers of a single platform.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ServerStatus {
+    pub entry_datetime: String,
+    pub environment: String,
+    pub limited_access: bool,
+    pub platform: String,
+    pub status: String,
+    pub version: String,
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl ServerStatus {
+    #[must_use]
+    pub fn is_up(&self) -> bool {
+        self.status.eq_ignore_ascii_case("up")
+    }
+}
+
+impl Client {
+    /// Returns the current patch version.
+    /// If a cache is configured and the version changed since the last call,
+    /// the cache is cleared.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_patch_info(&self) -> Result<PatchInfo> {
+        let patch_info: PatchInfo = self.make_request("getpatchinfo", true, &[]).await?;
+
+        if let Some(cache) = self.cache() {
+            cache.invalidate_for_patch(&patch_info.version_string);
+        }
+
+        Ok(patch_info)
+    }
+
+    /// Returns the status of Hi-Rez servers for every platform.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_server_status(&self) -> Result<Vec<ServerStatus>> {
+        self.make_request("gethirezserverstatus", true, &[]).await
+    }
+
+    /// Returns the status of Hi-Rez servers for the given platform, e.g. `"pc"`.
+    ///
+    /// # Errors
+    /// - If the request fails or if the response cannot be parsed.
+    /// - If the API does not report a status for the platform.
+    pub async fn get_platform_status(&self, platform: &str) -> Result<ServerStatus> {
+        self.get_server_status()
+            .await?
+            .into_iter()
+            .find(|status| status.platform.eq_ignore_ascii_case(platform))
+            .ok_or_else(|| Error::InvalidArgument {
+                given: platform.to_string(),
+                expected: "platform reported by gethirezserverstatus".to_string(),
+            })
+    }
+}
@@ -0,0 +1,150 @@
+use futures_util::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::client::Client;
+use crate::entities::ids::{PlayerId, TeamId};
+use crate::error::{Error, Result};
+
+use super::player::Player;
+
+/// Maximum number of `getplayer` requests made at once by `get_team_roster`.
+pub const ROSTER_CONCURRENCY: usize = 5;
+
+/// A clan, as referenced by `Player::team_id`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Team {
+    #[serde(rename(deserialize = "Founder"), alias = "founder")]
+    pub founder: String,
+    #[serde(
+        rename(deserialize = "FounderId"),
+        deserialize_with = "crate::utils::deserialize_non_empty_string",
+        alias = "founder_id"
+    )]
+    pub founder_id: Option<PlayerId>,
+    #[serde(rename(deserialize = "Losses"), alias = "losses")]
+    pub losses: u32,
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    pub name: String,
+    #[serde(rename(deserialize = "Players"), alias = "players")]
+    pub players: u32,
+    #[serde(rename(deserialize = "Rating"), alias = "rating")]
+    pub rating: u32,
+    #[serde(rename(deserialize = "Tag"), alias = "tag")]
+    pub tag: String,
+    #[serde(rename(deserialize = "TeamId"), alias = "team_id")]
+    pub team_id: TeamId,
+    #[serde(rename(deserialize = "Wins"), alias = "wins")]
+    pub wins: u32,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TeamMember {
+    #[serde(rename(deserialize = "AccountLevel"), alias = "account_level")]
+    pub account_level: u32,
+    #[serde(rename(deserialize = "JoinedDatetime"), alias = "joined_datetime")]
+    pub joined_datetime: String,
+    #[serde(
+        rename(deserialize = "LastLoginDatetime"),
+        alias = "last_login_datetime"
+    )]
+    pub last_login_datetime: String,
+    /// Name of the player, prefixed with the clan tag, e.g. `"[TAG]Player"`.
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    pub name: String,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TeamSearchResult {
+    #[serde(rename(deserialize = "Founder"), alias = "founder")]
+    pub founder: String,
+    #[serde(rename(deserialize = "Name"), alias = "name")]
+    pub name: String,
+    #[serde(rename(deserialize = "Players"), alias = "players")]
+    pub players: u32,
+    #[serde(rename(deserialize = "Tag"), alias = "tag")]
+    pub tag: String,
+    #[serde(rename(deserialize = "TeamId"), alias = "team_id")]
+    pub team_id: TeamId,
+    #[serde(rename(deserialize = "ret_msg"))]
+    pub ret_msg: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl TeamMember {
+    /// Returns the name of the player without the clan tag.
+    #[must_use]
+    pub fn player_name(&self) -> &str {
+        match self.name.split_once(']') {
+            Some((tag, name)) if tag.starts_with('[') => name,
+            _ => &self.name,
+        }
+    }
+}
+
+impl Client {
+    /// Returns information about a clan.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_team_details(&self, team_id: TeamId) -> Result<Vec<Team>> {
+        self.make_request("getteamdetails", true, &[&team_id.to_string()])
+            .await
+    }
+
+    /// Returns the members of a clan.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn get_team_players(&self, team_id: TeamId) -> Result<Vec<TeamMember>> {
+        self.make_request("getteamplayers", true, &[&team_id.to_string()])
+            .await
+    }
+
+    /// Returns clans whose name or tag contains `search`.
+    /// This method requires a valid session.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or if the response cannot be parsed.
+    pub async fn search_teams(&self, search: &str) -> Result<Vec<TeamSearchResult>> {
+        self.make_request("searchteams", true, &[search]).await
+    }
+
+    /// Returns the profiles of all members of a clan, in member order.
+    /// Makes one `getplayer` request per member, at most `ROSTER_CONCURRENCY` at once.
+    ///
+    /// Every member has a result, so a partial roster can be told apart from a complete
+    /// one. Members whose lookup fails keep the error of the request, and members that
+    /// can't be found in the clan, e.g. because their profile is hidden or they were
+    /// renamed, map to `Error::PlayerNameNotFound`.
+    ///
+    /// # Errors
+    /// Returns an error if the clan members can't be retrieved.
+    pub async fn get_team_roster(&self, team_id: TeamId) -> Result<Vec<Result<Player>>> {
+        let members = self.get_team_players(team_id).await?;
+        let lookups = members.iter().map(|member| async move {
+            let name = member.player_name();
+            self.get_player(name)
+                .await?
+                .into_iter()
+                .find(|player| player.team_id == team_id && !player.id.is_hidden())
+                .ok_or_else(|| Error::PlayerNameNotFound(name.to_string()))
+        });
+
+        Ok(stream::iter(lookups)
+            .buffered(ROSTER_CONCURRENCY)
+            .collect()
+            .await)
+    }
+}
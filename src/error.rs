@@ -1,4 +1,8 @@
+use std::sync::Arc;
+
 use serde_json::Value;
+
+use crate::entities::ids::PlayerId;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -17,4 +21,13 @@ pub enum Error {
     SmiteApi(String),
     #[error("Incorrect argument: {given}, expected: {expected}")]
     InvalidArgument { given: String, expected: String },
+    #[error("Player {0} not found.")]
+    PlayerNotFound(PlayerId),
+    #[error("Player {0} not found.")]
+    PlayerNameNotFound(String),
+    #[error("Player {0} has a private profile.")]
+    PrivateProfile(PlayerId),
+    /// A batch request failed. The error is shared by every id of the request.
+    #[error("Batch request failed: {0}")]
+    Batch(#[source] Arc<Error>),
 }
@@ -0,0 +1,446 @@
+//! Lenient parsing of API responses, see `Client::with_lenient_parsing`.
+//!
+//! Responses are deserialized through `ValueDeserializer`, whose error type records
+//! which field of which object was missing. The missing field is then filled with the
+//! first of `candidates` its type accepts and deserialization is retried.
+use std::fmt;
+
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Deserializes `value`, filling missing fields with a default of their type until it
+/// succeeds. Returns the parsed value and the paths of the defaulted fields, e.g.
+/// `"Account_Level"` or `"ItemDescription.Description"`. Indices of arrays are left out
+/// of paths, and a field missing from many elements of an array is reported once.
+///
+/// Returns `None` if the value can't be fixed this way.
+pub(crate) fn deserialize_with_defaults<T>(mut value: Value) -> Option<(T, Vec<String>)>
+where
+    T: DeserializeOwned,
+{
+    let candidates = candidates();
+    let mut defaulted: Vec<String> = Vec::new();
+    let mut trial: Option<Trial> = None;
+
+    loop {
+        let err = match T::deserialize(ValueDeserializer::new(&value, Vec::new())) {
+            Ok(parsed) => {
+                defaulted.extend(trial.map(|trial| trial.name));
+                return Some((parsed, defaulted));
+            }
+            Err(err) => err,
+        };
+
+        if let Some((path, field)) = err.missing {
+            defaulted.extend(trial.take().map(|trial| trial.name));
+
+            let name = field_name(&path, field);
+            if defaulted.contains(&name) {
+                return None;
+            }
+
+            let objects = insert_missing(&mut value, &path, field, &candidates[0]);
+            trial = Some(Trial {
+                name,
+                field,
+                objects,
+                candidate: 0,
+            });
+            continue;
+        }
+
+        // The field being filled rejected the candidate, so the next one is tried.
+        let trial = trial.as_mut()?;
+        trial.candidate += 1;
+        let candidate = candidates.get(trial.candidate)?;
+        for pointer in &trial.objects {
+            if let Some(object) = value.pointer_mut(pointer).and_then(Value::as_object_mut) {
+                object.insert(trial.field.to_string(), candidate.clone());
+            }
+        }
+    }
+}
+
+/// Defaults tried in order for a missing field. `0` also fits ids and flags,
+/// and an empty object lets the missing fields of a nested struct be filled in turn.
+fn candidates() -> [Value; 5] {
+    [
+        Value::from(0),
+        Value::from(""),
+        Value::Bool(false),
+        Value::Array(Vec::new()),
+        Value::Object(Map::new()),
+    ]
+}
+
+/// A missing field currently being filled.
+struct Trial {
+    name: String,
+    field: &'static str,
+    /// JSON pointers of the objects the field was inserted into.
+    objects: Vec<String>,
+    candidate: usize,
+}
+
+fn field_name(path: &[Segment], field: &str) -> String {
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Key(key) => Some(key.as_str()),
+            Segment::Index(_) => None,
+        })
+        .chain(std::iter::once(field))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Inserts `field` into every object lacking it at `path`, where indices match
+/// every element of their array. Returns JSON pointers of the objects changed.
+fn insert_missing(
+    value: &mut Value,
+    path: &[Segment],
+    field: &str,
+    default: &Value,
+) -> Vec<String> {
+    let mut pointers = Vec::new();
+    collect_pointers(value, path, String::new(), &mut pointers);
+
+    pointers.retain(|pointer| {
+        value
+            .pointer_mut(pointer)
+            .and_then(Value::as_object_mut)
+            .is_some_and(|object| {
+                if object.contains_key(field) {
+                    return false;
+                }
+                object.insert(field.to_string(), default.clone());
+                true
+            })
+    });
+
+    pointers
+}
+
+fn collect_pointers(value: &Value, path: &[Segment], pointer: String, out: &mut Vec<String>) {
+    let Some((segment, rest)) = path.split_first() else {
+        out.push(pointer);
+        return;
+    };
+
+    match (segment, value) {
+        (Segment::Key(key), Value::Object(object)) => {
+            if let Some(child) = object.get(key) {
+                let escaped = key.replace('~', "~0").replace('/', "~1");
+                collect_pointers(child, rest, format!("{pointer}/{escaped}"), out);
+            }
+        }
+        (Segment::Index(_), Value::Array(values)) => {
+            for (index, child) in values.iter().enumerate() {
+                collect_pointers(child, rest, format!("{pointer}/{index}"), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct LenientError {
+    message: String,
+    /// Path of the object missing the field, and the field itself.
+    missing: Option<(Vec<Segment>, &'static str)>,
+}
+
+impl LenientError {
+    /// Records `path` as the object missing the field, unless an inner object already did.
+    fn at(mut self, path: &[Segment]) -> LenientError {
+        if let Some((missing_path, _)) = &mut self.missing {
+            if missing_path.is_empty() {
+                *missing_path = path.to_vec();
+            }
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for LenientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for LenientError {}
+
+impl de::Error for LenientError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        LenientError {
+            message: msg.to_string(),
+            missing: None,
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        LenientError {
+            message: format!("missing field `{field}`"),
+            missing: Some((Vec::new(), field)),
+        }
+    }
+}
+
+struct ValueDeserializer<'de> {
+    value: &'de Value,
+    path: Vec<Segment>,
+}
+
+impl<'de> ValueDeserializer<'de> {
+    fn new(value: &'de Value, path: Vec<Segment>) -> Self {
+        ValueDeserializer { value, path }
+    }
+
+    fn child(&self, segment: Segment, value: &'de Value) -> Self {
+        let mut path = self.path.clone();
+        path.push(segment);
+
+        ValueDeserializer { value, path }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = LenientError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(flag) => visitor.visit_bool(*flag),
+            Value::Number(number) => {
+                if let Some(number) = number.as_u64() {
+                    visitor.visit_u64(number)
+                } else if let Some(number) = number.as_i64() {
+                    visitor.visit_i64(number)
+                } else {
+                    visitor.visit_f64(number.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(val) => visitor.visit_borrowed_str(val),
+            Value::Array(values) => visitor.visit_seq(SeqAccessor {
+                parent: &self,
+                values: values.iter().enumerate(),
+            }),
+            Value::Object(object) => visitor
+                .visit_map(MapAccessor {
+                    parent: &self,
+                    entries: object.iter(),
+                    value: None,
+                })
+                .map_err(|err| err.at(&self.path)),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::String(variant) => visitor.visit_enum(variant.as_str().into_deserializer()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqAccessor<'a, 'de> {
+    parent: &'a ValueDeserializer<'de>,
+    values: std::iter::Enumerate<std::slice::Iter<'de, Value>>,
+}
+
+impl<'de> SeqAccess<'de> for SeqAccessor<'_, 'de> {
+    type Error = LenientError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some((index, value)) = self.values.next() else {
+            return Ok(None);
+        };
+
+        seed.deserialize(self.parent.child(Segment::Index(index), value))
+            .map(Some)
+    }
+}
+
+struct MapAccessor<'a, 'de> {
+    parent: &'a ValueDeserializer<'de>,
+    entries: serde_json::map::Iter<'de>,
+    value: Option<(&'de String, &'de Value)>,
+}
+
+impl<'de> MapAccess<'de> for MapAccessor<'_, 'de> {
+    type Error = LenientError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.value = Some((key, value));
+
+        seed.deserialize(BorrowedStrDeserializer::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before key"))?;
+
+        seed.deserialize(self.parent.child(Segment::Key(key.clone()), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use serde::Deserialize;
+    use serde_json::{json, Map, Value};
+
+    use super::deserialize_with_defaults;
+    use crate::entities::ids::GodId;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Row {
+        #[serde(rename = "Kills")]
+        kills: u32,
+        #[serde(rename = "Name")]
+        name: String,
+        #[serde(rename = "GodId")]
+        god_id: GodId,
+        #[serde(rename = "Ranked", deserialize_with = "crate::utils::bool_from_string")]
+        ranked: bool,
+        #[serde(rename = "Surrendered")]
+        surrendered: bool,
+        #[serde(rename = "Items")]
+        items: Vec<u32>,
+        #[serde(rename = "Tag")]
+        tag: Option<String>,
+        #[serde(rename = "Details")]
+        details: Details,
+        #[serde(flatten)]
+        additional_fields: Map<String, Value>,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Details {
+        #[serde(rename = "Description")]
+        description: String,
+        #[serde(rename = "Cooldown")]
+        cooldown: f64,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Dated {
+        #[serde(deserialize_with = "crate::utils::timestamp_from_string")]
+        _date: DateTime<Utc>,
+    }
+
+    #[test]
+    fn defaults_missing_fields_by_type() {
+        let (row, defaulted): (Row, _) = deserialize_with_defaults(json!({ "Extra": 1 })).unwrap();
+
+        assert_eq!(row.kills, 0);
+        assert_eq!(row.name, "");
+        assert_eq!(row.god_id, GodId(0));
+        assert!(!row.ranked);
+        assert!(!row.surrendered);
+        assert!(row.items.is_empty());
+        assert_eq!(row.tag, None);
+        assert_eq!(row.details.description, "");
+        assert!(row.details.cooldown.abs() < f64::EPSILON);
+        assert_eq!(
+            row.additional_fields,
+            json!({ "Extra": 1 }).as_object().unwrap().clone()
+        );
+
+        assert!(defaulted.contains(&"Kills".to_string()));
+        assert!(defaulted.contains(&"Details.Description".to_string()));
+        assert!(!defaulted.contains(&"Tag".to_string()));
+    }
+
+    #[test]
+    fn reports_field_missing_from_many_rows_once() {
+        let row = json!({
+            "Kills": 3,
+            "Name": "Thor",
+            "GodId": "1943",
+            "Ranked": "y",
+            "Surrendered": false,
+            "Items": [],
+            "Details": { "Description": "", "Cooldown": 12.5 },
+        });
+        let mut missing = row.clone();
+        missing.as_object_mut().unwrap().remove("Kills");
+
+        let (rows, defaulted): (Vec<Row>, _) =
+            deserialize_with_defaults(json!([row, missing, missing])).unwrap();
+
+        assert_eq!(
+            rows.iter().map(|row| row.kills).collect::<Vec<_>>(),
+            [3, 0, 0]
+        );
+        assert_eq!(defaulted, ["Kills"]);
+    }
+
+    #[test]
+    fn fails_for_fields_without_a_default() {
+        assert!(deserialize_with_defaults::<Dated>(json!({})).is_none());
+    }
+}
@@ -16,7 +16,12 @@
 //!     Ok(())
 //! }
 //! ```
+pub mod analytics;
+pub mod cache;
 pub mod client;
 pub mod entities;
 pub mod error;
+mod lenient;
+#[cfg(test)]
+mod test_support;
 mod utils;
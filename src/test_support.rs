@@ -0,0 +1,93 @@
+//! Rows of `getmatchdetails` built in memory for unit tests.
+use crate::entities::game::player_game_info::PlayerGameInfo;
+use crate::entities::ids::{GodId, ItemId, MatchId, PartyId, PlayerId};
+
+const MATCH_DETAILS: &str = include_str!("../tests/fixtures/match_details_hidden_player.json");
+
+/// A Conquest row of `match_id` with no bans, items nor relics.
+pub(crate) fn row(
+    match_id: u64,
+    task_force: u32,
+    god_id: u32,
+    role: &str,
+    won: bool,
+) -> PlayerGameInfo {
+    let rows: Vec<PlayerGameInfo> = serde_json::from_str(MATCH_DETAILS).unwrap();
+    let mut row = rows[0].clone();
+
+    row.match_id = MatchId(match_id);
+    row.task_force = task_force;
+    row.winning_task_force = if won { task_force } else { 3 - task_force };
+    row.win_status = if won { "Winner" } else { "Loser" }.to_string();
+    row.player_id = PlayerId(god_id * 10 + task_force);
+    row.god_id = GodId(god_id);
+    row.reference_name = format!("God {god_id}");
+    row.role = role.to_string();
+    row.party_id = PartyId(0);
+    for ban in [
+        &mut row.ban1_id,
+        &mut row.ban2_id,
+        &mut row.ban3_id,
+        &mut row.ban4_id,
+        &mut row.ban5_id,
+        &mut row.ban6_id,
+        &mut row.ban7_id,
+        &mut row.ban8_id,
+        &mut row.ban9_id,
+        &mut row.ban10_id,
+        &mut row.ban11_id,
+        &mut row.ban12_id,
+    ] {
+        *ban = GodId(0);
+    }
+    for (id, name) in [
+        (&mut row.active_id1, &mut row.item_active_1),
+        (&mut row.active_id2, &mut row.item_active_2),
+        (&mut row.active_id3, &mut row.item_active_3),
+        (&mut row.active_id4, &mut row.item_active_4),
+    ] {
+        *id = ItemId(0);
+        name.clear();
+    }
+    set_items(&mut row, &[]);
+
+    row
+}
+
+/// Rows of a match between `team1` and `team2`, given as `(god id, role)` pairs.
+pub(crate) fn game(
+    match_id: u64,
+    team1: &[(u32, &str)],
+    team2: &[(u32, &str)],
+    team1_won: bool,
+) -> Vec<PlayerGameInfo> {
+    let team1 = team1
+        .iter()
+        .map(|(god, role)| row(match_id, 1, *god, role, team1_won));
+    let team2 = team2
+        .iter()
+        .map(|(god, role)| row(match_id, 2, *god, role, !team1_won));
+
+    team1.chain(team2).collect()
+}
+
+/// Sets `ItemId1..6` in order, clearing the remaining slots.
+pub(crate) fn set_items(row: &mut PlayerGameInfo, items: &[u32]) {
+    let slots = [
+        (&mut row.item_id1, &mut row.item_purch_1),
+        (&mut row.item_id2, &mut row.item_purch_2),
+        (&mut row.item_id3, &mut row.item_purch_3),
+        (&mut row.item_id4, &mut row.item_purch_4),
+        (&mut row.item_id5, &mut row.item_purch_5),
+        (&mut row.item_id6, &mut row.item_purch_6),
+    ];
+    for (index, (id, name)) in slots.into_iter().enumerate() {
+        let item = items.get(index).copied().unwrap_or(0);
+        *id = ItemId(item);
+        *name = if item == 0 {
+            String::new()
+        } else {
+            format!("Item {item}")
+        };
+    }
+}
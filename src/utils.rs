@@ -2,32 +2,90 @@ use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer};
+use serde_json::Value;
 
+/// Accepts a string, a number or `null`. `null`, `""` and `"\"\""` are treated as `None`.
 pub(crate) fn deserialize_non_empty_string<'de, D, T>(data: D) -> Result<Option<T>, D::Error>
 where
     D: Deserializer<'de>,
-    T: Deserialize<'de> + FromStr,
+    T: FromStr,
 {
-    if let Some(val) = Option::<String>::deserialize(data)? {
-        let trimmed = val.trim();
-        if val.trim().is_empty() || trimmed == "\"\"" {
-            return Ok(None);
+    let Some(val) = non_empty_value(Value::deserialize(data)?) else {
+        return Ok(None);
+    };
+
+    T::from_str(&val)
+        .map(Some)
+        .map_err(|_| serde::de::Error::custom(format!("Unable to parse value: {val:?}")))
+}
+
+/// Accepts both a number and a string containing a number, e.g. `123` and `"123"`.
+pub(crate) fn deserialize_string_or_number<'de, D, T>(data: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    deserialize_non_empty_string(data)?
+        .ok_or_else(|| serde::de::Error::custom("Expected a string or a number, found empty value"))
+}
+
+/// Like `deserialize_string_or_number`, but empty values and `null` become `T::default()`.
+pub(crate) fn deserialize_or_default<'de, D, T>(data: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Default,
+{
+    deserialize_non_empty_string(data).map(Option::unwrap_or_default)
+}
+
+/// The API reports flags as `"true"`, `"y"` or `"1"` strings, as well as real booleans
+/// and numbers. `""`, `"n"` and `null` mean `false`.
+pub(crate) fn bool_from_string<'de, D>(data: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let flag = match Value::deserialize(data)? {
+        Value::Bool(flag) => flag,
+        Value::Number(number) => number.as_f64().is_some_and(|number| number != 0.0),
+        Value::String(val) => {
+            let val = val.trim();
+            ["true", "y", "yes", "1"]
+                .iter()
+                .any(|truthy| val.eq_ignore_ascii_case(truthy))
         }
+        _ => false,
+    };
 
-        T::from_str(&val)
-            .map(Some)
-            .map_err(|_| serde::de::Error::custom(format!("Unable to parse value: {val:?}")))
-            .map_err(From::from)
-    } else {
-        Ok(None)
+    Ok(flag)
+}
+
+fn non_empty_value(value: Value) -> Option<String> {
+    let val = match value {
+        Value::String(val) => val,
+        Value::Number(number) => number.to_string(),
+        Value::Bool(flag) => flag.to_string(),
+        _ => return None,
+    };
+
+    let trimmed = val.trim();
+    if trimmed.is_empty() || trimmed == "\"\"" {
+        return None;
     }
+
+    Some(trimmed.to_string())
 }
 
+/// Accepts the API format, e.g. `"6/3/2024 7:12:45 PM"` in UTC, as well as RFC 3339,
+/// which is how `DateTime<Utc>` is serialized.
 pub(crate) fn timestamp_from_string<'de, D>(data: D) -> Result<DateTime<Utc>, D::Error>
 where
     D: Deserializer<'de>,
 {
     let timestamp = String::deserialize(data)?;
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(&timestamp) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+
     let timestamp = format!("{timestamp} +0000");
 
     DateTime::parse_from_str(&timestamp, "%-m/%d/%Y %I:%M:%S %p %z")
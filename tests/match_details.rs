@@ -0,0 +1,81 @@
+use smite::entities::game::match_details::Match;
+use smite::entities::game::player_game_info::PlayerGameInfo;
+use smite::entities::game::Queue;
+use smite::entities::ids::{MatchId, PartyId, PlayerId};
+use smite::error::Error;
+
+const HIDDEN_PLAYER_MATCH: &str = include_str!("fixtures/match_details_hidden_player.json");
+
+#[test]
+fn parses_match_with_hidden_player() {
+    let rows: Vec<PlayerGameInfo> = serde_json::from_str(HIDDEN_PLAYER_MATCH).unwrap();
+
+    assert_eq!(rows[0].player_id, PlayerId(8_012_345));
+    assert_eq!(rows[0].active_player_id, PlayerId(8_012_345));
+    assert!(rows[1].player_id.is_hidden());
+    assert!(rows[1].active_player_id.is_hidden());
+
+    let game = Match::from_players(rows).unwrap();
+    assert_eq!(game.players().count(), 2);
+    assert!(game.team(2).unwrap().players[0].player_id.is_hidden());
+}
+
+#[test]
+fn parses_missing_player_ids_as_hidden() {
+    let mut rows: Vec<serde_json::Value> = serde_json::from_str(HIDDEN_PLAYER_MATCH).unwrap();
+    let hidden = rows[1].as_object_mut().unwrap();
+    hidden.remove("playerId");
+    hidden.remove("ActivePlayerId");
+
+    let rows: Vec<PlayerGameInfo> = serde_json::from_value(serde_json::Value::Array(rows)).unwrap();
+    assert!(rows[1].player_id.is_hidden());
+}
+
+#[test]
+fn parses_typed_party_and_queue_ids() {
+    let rows: Vec<PlayerGameInfo> = serde_json::from_str(HIDDEN_PLAYER_MATCH).unwrap();
+
+    assert_eq!(rows[0].party_id, PartyId(3011));
+    assert!(rows[1].party_id.is_solo());
+    assert_eq!(rows[0].match_queue_id, Queue::Conquest);
+
+    let json = serde_json::to_value(&rows[0]).unwrap();
+    assert_eq!(json["party_id"], 3011);
+    assert_eq!(json["match_queue_id"], 426);
+}
+
+#[test]
+fn rejects_unknown_task_forces() {
+    let mut rows: Vec<PlayerGameInfo> = serde_json::from_str(HIDDEN_PLAYER_MATCH).unwrap();
+    rows[1].task_force = 3;
+
+    let err = Match::from_players(rows).unwrap_err();
+    assert!(matches!(err, Error::InvalidArgument { given, .. } if given == "task force 3"));
+}
+
+#[test]
+fn groups_rows_by_match_in_first_seen_order() {
+    let rows: Vec<PlayerGameInfo> = serde_json::from_str(HIDDEN_PLAYER_MATCH).unwrap();
+    let mut other = rows.clone();
+    let mut malformed = rows.clone();
+    for row in &mut other {
+        row.match_id = MatchId(2);
+    }
+    for row in &mut malformed {
+        row.match_id = MatchId(3);
+        row.task_force = 0;
+    }
+    let interleaved = vec![
+        other[0].clone(),
+        rows[0].clone(),
+        malformed[0].clone(),
+        other[1].clone(),
+        rows[1].clone(),
+    ];
+
+    let matches = Match::group(interleaved);
+
+    let ids: Vec<MatchId> = matches.iter().map(|game| game.id).collect();
+    assert_eq!(ids, [MatchId(2), rows[0].match_id]);
+    assert!(matches.iter().all(|game| game.players().count() == 2));
+}
@@ -0,0 +1,95 @@
+//! Entities parsed from the API must survive a serialize -> deserialize round-trip,
+//! so that they can be stored and read back.
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use smite::entities::esports::ProLeagueMatch;
+use smite::entities::game::player_game_info::PlayerGameInfo;
+use smite::entities::game::top_matches::TopMatch;
+use smite::entities::game::{MatchInfo, QueueMatch};
+use smite::entities::gods::alt_abilities::AltAbility;
+use smite::entities::gods::leaderboard::GodLeaderboardEntry;
+use smite::entities::gods::recommended_items::RecommendedItem;
+use smite::entities::gods::skins::GodSkin;
+use smite::entities::gods::God;
+use smite::entities::items::Item;
+use smite::entities::motd::MatchOfTheDay;
+use smite::entities::player::achievements::PlayerAchievements;
+use smite::entities::player::friends::Friend;
+use smite::entities::player::loadouts::GodLoadout;
+use smite::entities::player::Player;
+use smite::entities::session_info::SessionInfo;
+use smite::entities::status::{PatchInfo, ServerStatus};
+use smite::entities::team::{Team, TeamMember, TeamSearchResult};
+
+fn assert_round_trip<T>(api_json: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let parsed: T = serde_json::from_str(api_json).unwrap();
+    assert!(
+        serde_json::to_value(&parsed)
+            .unwrap()
+            .get("additional_fields")
+            .is_none(),
+        "additional fields must be flattened"
+    );
+
+    let stored = serde_json::to_string(&parsed).unwrap();
+    let restored: T = serde_json::from_str(&stored).unwrap();
+
+    assert_eq!(parsed, restored);
+}
+
+macro_rules! round_trip_tests {
+    ($($test:ident: $entity:ty => $fixture:literal,)*) => {
+        $(
+            #[test]
+            fn $test() {
+                assert_round_trip::<$entity>(include_str!(concat!("fixtures/", $fixture)));
+            }
+        )*
+    };
+}
+
+round_trip_tests! {
+    alt_ability: AltAbility => "alt_ability.json",
+    friend: Friend => "friend.json",
+    god: God => "god.json",
+    god_leaderboard_entry: GodLeaderboardEntry => "god_leaderboard_entry.json",
+    god_loadout: GodLoadout => "god_loadout.json",
+    god_skin: GodSkin => "god_skin.json",
+    item: Item => "item.json",
+    match_details: Vec<PlayerGameInfo> => "match_details_hidden_player.json",
+    match_info: MatchInfo => "match_info.json",
+    match_of_the_day: MatchOfTheDay => "match_of_the_day.json",
+    patch_info: PatchInfo => "patch_info.json",
+    player: Player => "player.json",
+    player_achievements: PlayerAchievements => "player_achievements.json",
+    pro_league_match: ProLeagueMatch => "pro_league_match.json",
+    queue_match: QueueMatch => "queue_match.json",
+    recommended_item: RecommendedItem => "recommended_item.json",
+    server_status: ServerStatus => "server_status.json",
+    session_info: SessionInfo => "session_info.json",
+    team: Team => "team.json",
+    team_member: TeamMember => "team_member.json",
+    team_search_result: TeamSearchResult => "team_search_result.json",
+    top_match: TopMatch => "top_match.json",
+}
+
+#[test]
+fn match_info_keeps_only_unknown_fields() {
+    let info: MatchInfo = serde_json::from_str(include_str!("fixtures/match_info.json")).unwrap();
+
+    let mut unknown: Vec<&String> = info.player_info.additional_fields.keys().collect();
+    unknown.sort();
+    assert_eq!(unknown, ["Match", "Queue"]);
+
+    #[allow(deprecated)]
+    let deprecated = &info.additional_fields;
+    assert_eq!(deprecated, &info.player_info.additional_fields);
+    let json = serde_json::to_value(&info).unwrap();
+    assert!(json.get("additional_fields").is_none());
+}